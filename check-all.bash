@@ -10,4 +10,8 @@ for task in doc clippy check test; do
   just $task --all-features "$@"
   just $task --features std "$@"
   just $task --features parking_lot "$@"
+  just $task --features serde "$@"
+  just $task --no-default-features --features serde "$@"
+  just $task --features sync_db "$@"
+  just $task --features checksum "$@"
 done
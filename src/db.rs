@@ -0,0 +1,5 @@
+//! Readers of pacman databases.
+
+pub mod local;
+#[cfg(feature = "sync_db")]
+pub mod sync;
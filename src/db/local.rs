@@ -0,0 +1,217 @@
+//! Reader of the local database such as `/var/lib/pacman/local`.
+
+use crate::{
+    files,
+    parse::{DescParseError, ParsedDesc},
+};
+use derive_more::{Display, Error};
+use pipe_trait::Pipe;
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+/// Name of the file that holds the version of the layout of the local database.
+pub const VERSION_FILE_NAME: &str = "ALPM_DB_VERSION";
+
+/// Version of the layout of the local database which is supported by [`LocalDb`].
+pub const SUPPORTED_VERSION: &str = "9";
+
+/// Content of a local database.
+///
+/// The database is a directory of package directories named `{name}-{version}` (such as
+/// `bash-5.2.037-1`), each of which contains a `desc` file, a `files` file, and optionally
+/// an `mtree` file.
+#[derive(Debug, Default, Clone)]
+pub struct LocalDb {
+    entries: BTreeMap<String, LocalDbEntry>,
+}
+
+/// Content of a package directory.
+#[derive(Debug, Default, Clone)]
+struct LocalDbEntry {
+    directory: String,
+    desc: String,
+    files: Option<String>,
+    mtree: Option<Vec<u8>>,
+}
+
+/// Package of a [`LocalDb`].
+#[derive(Debug, Clone, Copy)]
+pub struct LocalDbPackage<'a> {
+    /// Name of the package as inferred from the name of its directory.
+    pub name: &'a str,
+    /// Name of the directory of the package.
+    pub directory: &'a str,
+    /// Parsed `desc` file of the package.
+    pub desc: ParsedDesc<'a>,
+    /// Querier of the `files` file of the package, if the directory has one.
+    pub files: Option<files::ForgetfulQuerier<'a>>,
+    /// Content of the gzip compressed `mtree` file of the package, if the directory has one.
+    pub mtree: Option<&'a [u8]>,
+}
+
+/// Error type of [`LocalDb::open`].
+#[derive(Debug, Display, Error)]
+pub enum LoadLocalDbError {
+    #[display("Failed to read {}: {_1}", _0.display())]
+    Io(PathBuf, io::Error),
+    #[display("{VERSION_FILE_NAME} not found")]
+    MissingVersion,
+    #[display("Unsupported database version: {_0:?}")]
+    UnsupportedVersion(#[error(not(source))] String),
+    #[display("Path is not valid UTF-8: {_0:?}")]
+    NonUtf8Path(#[error(not(source))] PathBuf),
+    #[display("Content of {} is not valid UTF-8", _0.display())]
+    NonUtf8Content(#[error(not(source))] PathBuf),
+    #[display("Directory name is not in the form of name-pkgver-pkgrel: {_0:?}")]
+    InvalidDirectoryName(#[error(not(source))] String),
+    #[display("Directory {_0:?} has no desc file")]
+    MissingDesc(#[error(not(source))] String),
+    #[display("Package {_0:?} is installed more than once")]
+    DuplicatedPackage(#[error(not(source))] String),
+}
+
+/// Error type of [`LocalDb::get`] and [`LocalDb::packages`].
+#[derive(Debug, Display, Error, Clone, Copy)]
+pub enum LocalDbPackageError<'a> {
+    #[display("Failed to parse the desc file of {_0}: {_1}")]
+    Desc(&'a str, DescParseError<'a>),
+}
+
+/// Read a file into a [`String`], return `None` if the file doesn't exist.
+fn read_optional_string(path: PathBuf) -> Result<Option<String>, LoadLocalDbError> {
+    match fs::read(&path) {
+        Ok(content) => String::from_utf8(content)
+            .map(Some)
+            .map_err(|_| LoadLocalDbError::NonUtf8Content(path)),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(LoadLocalDbError::Io(path, error)),
+    }
+}
+
+/// Read a file into a [`Vec`], return `None` if the file doesn't exist.
+fn read_optional_bytes(path: PathBuf) -> Result<Option<Vec<u8>>, LoadLocalDbError> {
+    match fs::read(&path) {
+        Ok(content) => Ok(Some(content)),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(LoadLocalDbError::Io(path, error)),
+    }
+}
+
+/// Extract the package name from a directory name of the form `{name}-{pkgver}-{pkgrel}`.
+fn package_name(directory: &str) -> Option<&str> {
+    let mut segments = directory.rsplitn(3, '-');
+    let _release = segments.next()?;
+    let _version = segments.next()?;
+    segments.next().filter(|name| !name.is_empty())
+}
+
+impl LocalDb {
+    /// Load a local database from its root directory.
+    ///
+    /// The content of [`VERSION_FILE_NAME`] must be [`SUPPORTED_VERSION`].
+    pub fn open(root: impl AsRef<Path>) -> Result<Self, LoadLocalDbError> {
+        let root = root.as_ref();
+
+        fs::metadata(root).map_err(|error| LoadLocalDbError::Io(root.to_path_buf(), error))?;
+        let version = root
+            .join(VERSION_FILE_NAME)
+            .pipe(read_optional_string)?
+            .ok_or(LoadLocalDbError::MissingVersion)?;
+        if version.trim() != SUPPORTED_VERSION {
+            return Err(LoadLocalDbError::UnsupportedVersion(
+                version.trim().to_string(),
+            ));
+        }
+
+        let mut entries = BTreeMap::new();
+        let read_dir = |error| LoadLocalDbError::Io(root.to_path_buf(), error);
+        for dir_entry in fs::read_dir(root).map_err(read_dir)? {
+            let dir_entry = dir_entry.map_err(read_dir)?;
+            let path = dir_entry.path();
+            let file_type = dir_entry
+                .file_type()
+                .map_err(|error| LoadLocalDbError::Io(path.clone(), error))?;
+            if !file_type.is_dir() {
+                continue;
+            }
+
+            let directory = dir_entry
+                .file_name()
+                .into_string()
+                .map_err(|_| LoadLocalDbError::NonUtf8Path(path.clone()))?;
+            let name = package_name(&directory)
+                .ok_or_else(|| LoadLocalDbError::InvalidDirectoryName(directory.clone()))?
+                .to_string();
+            let desc = path
+                .join("desc")
+                .pipe(read_optional_string)?
+                .ok_or_else(|| LoadLocalDbError::MissingDesc(directory.clone()))?;
+            let files = path.join("files").pipe(read_optional_string)?;
+            let mtree = path.join("mtree").pipe(read_optional_bytes)?;
+
+            if entries.contains_key(&name) {
+                return Err(LoadLocalDbError::DuplicatedPackage(name));
+            }
+            let entry = LocalDbEntry {
+                directory,
+                desc,
+                files,
+                mtree,
+            };
+            entries.insert(name, entry);
+        }
+
+        Ok(LocalDb { entries })
+    }
+
+    /// Number of installed packages.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether there are no installed packages.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// List the names of the installed packages in alphabetical order.
+    pub fn names(&self) -> impl Iterator<Item = &'_ str> {
+        self.entries.keys().map(String::as_str)
+    }
+
+    /// Parse and get an installed package by its name.
+    pub fn get(&self, name: &str) -> Option<Result<LocalDbPackage<'_>, LocalDbPackageError<'_>>> {
+        let (name, entry) = self.entries.get_key_value(name)?;
+        Some(LocalDb::package(name, entry))
+    }
+
+    /// Parse and list the installed packages in alphabetical order of their names.
+    pub fn packages(
+        &self,
+    ) -> impl Iterator<Item = Result<LocalDbPackage<'_>, LocalDbPackageError<'_>>> {
+        self.entries
+            .iter()
+            .map(|(name, entry)| LocalDb::package(name, entry))
+    }
+
+    /// Parse a package.
+    fn package<'a>(
+        name: &'a str,
+        entry: &'a LocalDbEntry,
+    ) -> Result<LocalDbPackage<'a>, LocalDbPackageError<'a>> {
+        let directory = entry.directory.as_str();
+        let desc = ParsedDesc::parse(&entry.desc)
+            .map_err(|error| LocalDbPackageError::Desc(directory, error))?;
+        Ok(LocalDbPackage {
+            name,
+            directory,
+            desc,
+            files: entry.files.as_deref().map(files::ForgetfulQuerier::new),
+            mtree: entry.mtree.as_deref(),
+        })
+    }
+}
@@ -0,0 +1,167 @@
+//! Reader of sync database archives such as `core.db` and `extra.files`.
+
+use crate::{
+    files,
+    parse::{DescParseError, ParsedDesc},
+};
+use derive_more::{Display, Error};
+use flate2::read::MultiGzDecoder;
+use indexmap::IndexMap;
+use pipe_trait::Pipe;
+use ruzstd::decoding::StreamingDecoder;
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+/// Magic bytes at the start of a gzip stream.
+const GZIP_MAGIC: &[u8] = &[0x1F, 0x8B];
+/// Magic bytes at the start of a zstd frame.
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xB5, 0x2F, 0xFD];
+
+/// Content of a sync database archive.
+///
+/// The archive is a tarball (either plain, gzip compressed, or zstd compressed) of directories
+/// named after the packages (such as `bash-5.2.037-1`), each of which contains a `desc` file and,
+/// in the case of `.files` databases, a `files` file.
+#[derive(Debug, Default, Clone)]
+pub struct SyncDb {
+    entries: IndexMap<String, SyncDbEntry>,
+}
+
+/// Text of the files under a package directory.
+#[derive(Debug, Default, Clone)]
+struct SyncDbEntry {
+    desc: Option<String>,
+    files: Option<String>,
+}
+
+/// Package of a [`SyncDb`].
+#[derive(Debug, Clone, Copy)]
+pub struct SyncDbPackage<'a> {
+    /// Name of the directory of the package in the archive.
+    pub directory: &'a str,
+    /// Parsed `desc` file of the package.
+    pub desc: ParsedDesc<'a>,
+    /// Querier of the `files` file of the package, if the archive has one.
+    pub files: Option<files::ForgetfulQuerier<'a>>,
+}
+
+/// Error type of [`SyncDb::read`] and [`SyncDb::open`].
+#[derive(Debug, Display, Error)]
+pub enum ReadSyncDbError {
+    #[display("Failed to read the archive: {_0}")]
+    Io(io::Error),
+    #[display("Path of an entry is not valid UTF-8: {_0:?}")]
+    NonUtf8Path(#[error(not(source))] PathBuf),
+    #[display("Content of {_0} is not valid UTF-8")]
+    NonUtf8Content(#[error(not(source))] String),
+}
+
+impl From<io::Error> for ReadSyncDbError {
+    fn from(error: io::Error) -> Self {
+        ReadSyncDbError::Io(error)
+    }
+}
+
+/// Error type of [`SyncDb::packages`].
+#[derive(Debug, Display, Error, Clone, Copy)]
+pub enum SyncDbPackageError<'a> {
+    #[display("Directory {_0} has no desc file")]
+    MissingDesc(#[error(not(source))] &'a str),
+    #[display("Failed to parse the desc file of {_0}: {_1}")]
+    Desc(&'a str, DescParseError<'a>),
+}
+
+impl SyncDb {
+    /// Read a sync database archive.
+    ///
+    /// The compression (none, gzip, or zstd) is detected from the first bytes of the archive.
+    pub fn read(reader: impl Read) -> Result<Self, ReadSyncDbError> {
+        let mut reader = BufReader::new(reader);
+        let magic = reader.fill_buf()?;
+        if magic.starts_with(GZIP_MAGIC) {
+            reader.pipe(MultiGzDecoder::new).pipe(SyncDb::read_tar)
+        } else if magic.starts_with(ZSTD_MAGIC) {
+            reader
+                .pipe(StreamingDecoder::new)
+                .map_err(io::Error::other)?
+                .pipe(SyncDb::read_tar)
+        } else {
+            SyncDb::read_tar(reader)
+        }
+    }
+
+    /// Read a sync database archive from the file system, see [`SyncDb::read`].
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ReadSyncDbError> {
+        path.pipe(File::open)?.pipe(SyncDb::read)
+    }
+
+    /// Read an uncompressed tarball.
+    fn read_tar(reader: impl Read) -> Result<Self, ReadSyncDbError> {
+        let mut entries = IndexMap::<String, SyncDbEntry>::new();
+
+        for entry in tar::Archive::new(reader).entries()? {
+            let mut entry = entry?;
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+
+            let path = entry.path()?;
+            let path = path
+                .to_str()
+                .ok_or_else(|| ReadSyncDbError::NonUtf8Path(path.to_path_buf()))?
+                .to_string();
+            let Some((directory, file_name)) = path.trim_start_matches("./").split_once('/') else {
+                continue;
+            };
+            if file_name != "desc" && file_name != "files" {
+                continue;
+            }
+
+            let mut content = Vec::new();
+            entry.read_to_end(&mut content)?;
+            let content = String::from_utf8(content)
+                .map_err(|_| ReadSyncDbError::NonUtf8Content(path.clone()))?;
+
+            let slot = entries.entry(directory.to_string()).or_default();
+            match file_name {
+                "desc" => slot.desc = Some(content),
+                _ => slot.files = Some(content),
+            }
+        }
+
+        Ok(SyncDb { entries })
+    }
+
+    /// Number of package directories in the archive.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the archive has no package directories.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Parse and list the packages in the order they appear in the archive.
+    pub fn packages(
+        &self,
+    ) -> impl Iterator<Item = Result<SyncDbPackage<'_>, SyncDbPackageError<'_>>> {
+        self.entries.iter().map(|(directory, entry)| {
+            let desc = entry
+                .desc
+                .as_deref()
+                .ok_or(SyncDbPackageError::MissingDesc(directory))?
+                .pipe(ParsedDesc::parse)
+                .map_err(|error| SyncDbPackageError::Desc(directory, error))?;
+            let files = entry.files.as_deref().map(files::ForgetfulQuerier::new);
+            Ok(SyncDbPackage {
+                directory,
+                desc,
+                files,
+            })
+        })
+    }
+}
@@ -5,7 +5,7 @@ use strum::{AsRefStr, Display, EnumString, IntoStaticStr};
 #[derive(Debug, Clone, Copy, Eq, PartialEq)] // core traits
 #[derive(AsRef, Deref, derive_more::Display)] // derive_more traits
 #[display("%{_0}%")]
-pub struct Field<Name>(Name);
+pub struct Field<Name>(pub(crate) Name);
 
 impl<Name> Field<Name> {
     /// Get an immutable reference to the name of the field.
@@ -101,6 +101,16 @@ pub enum FieldName {
     Conflicts,
     #[strum(serialize = "REPLACES")]
     Replaces,
+    #[strum(serialize = "INSTALLDATE")]
+    InstallDate,
+    #[strum(serialize = "REASON")]
+    Reason,
+    #[strum(serialize = "VALIDATION")]
+    Validation,
+    #[strum(serialize = "SIZE")]
+    Size,
+    #[strum(serialize = "XDATA")]
+    XData,
 }
 
 mod parse;
@@ -58,11 +58,17 @@ def_traits! {
     provides, provides_mut = Provides -> DependencyList;
     conflicts, conflicts_mut = Conflicts -> DependencyList;
     replaces, replaces_mut = Replaces -> DependencyList;
+    install_date, install_date_mut = InstallDate -> Timestamp;
+    reason, reason_mut = Reason -> InstallReason;
+    validation, validation_mut = Validation -> ValidationList;
+    size, size_mut = Size -> Size;
+    xdata, xdata_mut = XData -> XDataList;
 }
 
 mod forgetful;
 mod generic;
 mod memo;
+pub(crate) mod utils;
 
 pub use crate::parse::ParsedDesc as EagerQuerier;
 pub use forgetful::ForgetfulQuerier;
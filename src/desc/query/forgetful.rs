@@ -1,9 +1,9 @@
 use core::convert::Infallible;
 
-use super::{Query, QueryMut};
+use super::{Query, QueryMut, utils::find_raw_value};
 use crate::{
     desc::{
-        field::{ParsedField, RawField},
+        field::ParsedField,
         misc::{False, ReuseAdvice},
     },
     parse::{ParseWithIssues, PartialParse, PartialParseResult},
@@ -23,28 +23,7 @@ impl<'a> ForgetfulQuerier<'a> {
 
 impl<'a> Query<'a> for ForgetfulQuerier<'a> {
     fn query_raw_text(&self, field: ParsedField) -> Option<&'a str> {
-        let mut lines_with_end_offset = self.0.lines().map(|line| {
-            (
-                line,
-                line.as_ptr() as usize + line.len() - self.0.as_ptr() as usize,
-            )
-        });
-
-        let (_, value_start_offset) = lines_with_end_offset.find(|(line, _)| {
-            line.trim()
-                .pipe(RawField::try_from)
-                .ok()
-                .map(|x| x.name_str() == field.name_str())
-                .unwrap_or(false)
-        })?;
-
-        let (_, value_end_offset) = lines_with_end_offset
-            .take_while(|(line, _)| RawField::try_from(line.trim()).is_err())
-            .last()?; // no last means empty iterator, which means no content
-
-        let value = self.0[value_start_offset..value_end_offset].trim_matches(['\n', '\r']);
-
-        if value.is_empty() { None } else { Some(value) }
+        find_raw_value(self.0, field.name_str())
     }
 }
 
@@ -1,7 +1,7 @@
-use super::QueryMut;
+use super::{QueryMut, utils::RawEntries};
 use crate::{
     desc::{
-        field::{FieldName, ParsedField, RawField},
+        field::{FieldName, ParsedField},
         misc::{ReuseAdvice, True},
     },
     parse::{ParseWithIssues, PartialParse, PartialParseResult},
@@ -12,59 +12,19 @@ use pipe_trait::Pipe;
 /// [Query](QueryMut) with a cache.
 #[derive(Debug, Clone)]
 pub struct MemoQuerier<'a> {
-    text: &'a str,
+    entries: RawEntries<'a>,
     cache: Cache<'a>,
-    last: Option<(&'a str, RawField<'a>)>,
 }
 
 impl<'a> MemoQuerier<'a> {
     /// Query the `text` with a cache.
     pub fn new(text: &'a str) -> Self {
         MemoQuerier {
-            text,
+            entries: RawEntries::new(text),
             cache: Cache::default(),
-            last: None,
         }
     }
 
-    /// Parse the next key-value pair, save it to cache and return it.
-    fn next_entry(&mut self) -> Option<(RawField<'a>, &'a str)> {
-        let mut lines = self.text.lines();
-
-        let (field_str, raw_field) = if let Some((field_str, raw_field)) = self.last {
-            lines.next()?;
-            (field_str, raw_field)
-        } else {
-            let field_str = lines.next()?.trim();
-            let raw_field = RawField::parse_raw(field_str).ok()?;
-            (field_str, raw_field)
-        };
-
-        let value_start_offset =
-            field_str.as_ptr() as usize + field_str.len() - self.text.as_ptr() as usize;
-        let next = lines.find_map(|line| -> Option<(&'a str, RawField<'a>)> {
-            let field_str = line.trim();
-            let raw_field = RawField::parse_raw(field_str).ok()?;
-            Some((field_str, raw_field))
-        });
-
-        let Some((next_field_str, next_raw_field)) = next else {
-            let value = self.text[value_start_offset..].trim_matches(['\n', '\r']);
-            self.text = "";
-            self.last = None;
-            return Some((raw_field, value));
-        };
-
-        let value_end_offset = next_field_str.as_ptr() as usize - self.text.as_ptr() as usize;
-        let value = self.text[value_start_offset..value_end_offset].trim_matches(['\n', '\r']);
-
-        // prepare for the next call
-        self.last = Some((next_field_str, next_raw_field));
-        self.text = &self.text[value_end_offset..];
-
-        Some((raw_field, value))
-    }
-
     /// Private function for testing the internal cache.
     #[doc(hidden)]
     pub fn __has_cache(&self, field: FieldName) -> bool {
@@ -78,7 +38,7 @@ impl<'a> QueryMut<'a> for MemoQuerier<'a> {
             return value;
         }
 
-        while let Some((raw_field, value)) = self.next_entry() {
+        for (raw_field, value) in self.entries.by_ref() {
             let Ok(parsed_field) = raw_field.to_parsed::<FieldName>() else {
                 continue;
             };
@@ -158,6 +118,7 @@ def_cache!(
     PgpSignature Url License Architecture BuildDate Packager
     Dependencies CheckDependencies MakeDependencies OptionalDependencies
     Provides Conflicts Replaces
+    InstallDate Reason Validation Size XData
 );
 
 impl ReuseAdvice for MemoQuerier<'_> {
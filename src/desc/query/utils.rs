@@ -0,0 +1,84 @@
+use crate::desc::field::RawField;
+use pipe_trait::Pipe;
+
+/// Find the raw value of a field in a text with the `desc` syntax.
+pub fn find_raw_value<'a>(text: &'a str, field_name: &str) -> Option<&'a str> {
+    let mut lines_with_end_offset = text.lines().map(|line| {
+        (
+            line,
+            line.as_ptr() as usize + line.len() - text.as_ptr() as usize,
+        )
+    });
+
+    let (_, value_start_offset) = lines_with_end_offset.find(|(line, _)| {
+        line.trim()
+            .pipe(RawField::parse_raw)
+            .ok()
+            .map(|x| x.name_str() == field_name)
+            .unwrap_or(false)
+    })?;
+
+    let (_, value_end_offset) = lines_with_end_offset
+        .take_while(|(line, _)| RawField::parse_raw(line.trim()).is_err())
+        .last()?; // no last means empty iterator, which means no content
+
+    let value = text[value_start_offset..value_end_offset].trim_matches(['\n', '\r']);
+
+    if value.is_empty() { None } else { Some(value) }
+}
+
+/// [Iterator] of the raw fields and values of a text with the `desc` syntax.
+#[derive(Debug, Clone)]
+pub struct RawEntries<'a> {
+    text: &'a str,
+    last: Option<(&'a str, RawField<'a>)>,
+}
+
+impl<'a> RawEntries<'a> {
+    /// Start iterating from the beginning of the text.
+    pub fn new(text: &'a str) -> Self {
+        RawEntries { text, last: None }
+    }
+}
+
+impl<'a> Iterator for RawEntries<'a> {
+    type Item = (RawField<'a>, &'a str);
+
+    /// Parse the next key-value pair.
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut lines = self.text.lines();
+
+        let (field_str, raw_field) = if let Some((field_str, raw_field)) = self.last {
+            lines.next()?;
+            (field_str, raw_field)
+        } else {
+            let field_str = lines.next()?.trim();
+            let raw_field = RawField::parse_raw(field_str).ok()?;
+            (field_str, raw_field)
+        };
+
+        let value_start_offset =
+            field_str.as_ptr() as usize + field_str.len() - self.text.as_ptr() as usize;
+        let next = lines.find_map(|line| -> Option<(&'a str, RawField<'a>)> {
+            let field_str = line.trim();
+            let raw_field = RawField::parse_raw(field_str).ok()?;
+            Some((field_str, raw_field))
+        });
+
+        let Some((next_field_str, next_raw_field)) = next else {
+            let value = self.text[value_start_offset..].trim_matches(['\n', '\r']);
+            self.text = "";
+            self.last = None;
+            return Some((raw_field, value));
+        };
+
+        let value_end_offset = next_field_str.as_ptr() as usize - self.text.as_ptr() as usize;
+        let value = self.text[value_start_offset..value_end_offset].trim_matches(['\n', '\r']);
+
+        // prepare for the next call
+        self.last = Some((next_field_str, next_raw_field));
+        self.text = &self.text[value_end_offset..];
+
+        Some((raw_field, value))
+    }
+}
@@ -0,0 +1,9 @@
+//! Fields and queriers of the text format of `files` entries of pacman databases.
+
+pub mod misc;
+
+mod field;
+pub use field::*;
+
+mod query;
+pub use query::*;
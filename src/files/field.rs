@@ -0,0 +1,35 @@
+use strum::{AsRefStr, Display, EnumString, IntoStaticStr};
+
+pub use crate::desc::{Field, ParseFieldError, ParseRawFieldError, RawField};
+
+/// Parsed field of a `files` entry.
+pub type ParsedField = Field<FieldName>;
+
+impl ParsedField {
+    /// Create a new [`ParsedField`].
+    pub const fn new(name: FieldName) -> Self {
+        Field(name)
+    }
+
+    /// Get the name of the field as a string slice.
+    pub fn name_str(&self) -> &'static str {
+        self.name().into()
+    }
+}
+
+impl From<FieldName> for ParsedField {
+    fn from(value: FieldName) -> Self {
+        ParsedField::new(value)
+    }
+}
+
+/// Field name of a `files` entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)] // core traits
+#[derive(AsRefStr, Display, EnumString, IntoStaticStr)] // strum traits
+#[strum(use_phf)]
+pub enum FieldName {
+    #[strum(serialize = "FILES")]
+    Files,
+    #[strum(serialize = "BACKUP")]
+    Backup,
+}
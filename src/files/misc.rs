@@ -0,0 +1,8 @@
+//! Miscellaneous items related to [`files`](super).
+
+mod derive_query_mut;
+
+pub use crate::desc::misc::{
+    False, ReuseAdvice, ReuseAdviceBool, ReuseAdviceSelf, ShouldReuse, StaticBool, True,
+};
+pub use derive_query_mut::*;
@@ -0,0 +1,47 @@
+use crate::{
+    files::field::{FieldName, ParsedField},
+    value,
+};
+
+macro_rules! def_traits {
+    ($(
+        $(#[$attrs:meta])*
+        $name:ident, $name_mut:ident = $field_name:ident -> $value_type:ident;
+    )*) => {
+        /// Get information from a `files` entry.
+        pub trait Query<'a> {
+            fn query_raw_text(&self, field: ParsedField) -> Option<&'a str>;
+            $(
+                $(#[$attrs])*
+                fn $name(&self) -> Option<value::$value_type<'a>> {
+                    self.query_raw_text(ParsedField::new(FieldName::$field_name))
+                        .map(value::$value_type::new)
+                }
+            )*
+        }
+
+        /// Get information from a `files` entry, mutability required.
+        pub trait QueryMut<'a> {
+            fn query_raw_text_mut(&mut self, field: ParsedField) -> Option<&'a str>;
+            $(
+                $(#[$attrs])*
+                fn $name_mut(&mut self) -> Option<value::$value_type<'a>> {
+                    self.query_raw_text_mut(ParsedField::new(FieldName::$field_name))
+                        .map(value::$value_type::new)
+                }
+            )*
+        }
+    };
+}
+
+def_traits! {
+    files, files_mut = Files -> FilePathList;
+    backup, backup_mut = Backup -> BackupList;
+}
+
+mod forgetful;
+mod generic;
+mod memo;
+
+pub use forgetful::ForgetfulQuerier;
+pub use memo::MemoQuerier;
@@ -0,0 +1,63 @@
+use core::convert::Infallible;
+
+use super::{Query, QueryMut};
+use crate::{
+    desc::utils::find_raw_value,
+    files::{
+        field::ParsedField,
+        misc::{False, ReuseAdvice},
+    },
+    parse::{ParseWithIssues, PartialParse, PartialParseResult},
+};
+use pipe_trait::Pipe;
+
+/// [Query] without a cache.
+#[derive(Debug, Clone, Copy)]
+pub struct ForgetfulQuerier<'a>(&'a str);
+
+impl<'a> ForgetfulQuerier<'a> {
+    /// Query the `text` without cache.
+    pub const fn new(text: &'a str) -> Self {
+        ForgetfulQuerier(text)
+    }
+}
+
+impl<'a> Query<'a> for ForgetfulQuerier<'a> {
+    fn query_raw_text(&self, field: ParsedField) -> Option<&'a str> {
+        find_raw_value(self.0, field.name_str())
+    }
+}
+
+impl<'a> QueryMut<'a> for ForgetfulQuerier<'a> {
+    fn query_raw_text_mut(&mut self, field: ParsedField) -> Option<&'a str> {
+        self.query_raw_text(field)
+    }
+}
+
+impl ReuseAdvice for ForgetfulQuerier<'_> {
+    /// Whilst [`ForgetfulQuerier`] costs nothing to construct, performing a
+    /// lookup on it costs O(n) time complexity (n being text length).
+    ///
+    /// This struct is best used to lookup once.
+    type ShouldReuse = False;
+}
+
+impl<'a> From<&'a str> for ForgetfulQuerier<'a> {
+    fn from(value: &'a str) -> Self {
+        ForgetfulQuerier::new(value)
+    }
+}
+
+impl<'a> PartialParse<&'a str> for ForgetfulQuerier<'a> {
+    type Error = Infallible;
+    fn partial_parse(text: &'a str) -> PartialParseResult<Self, Self::Error> {
+        ForgetfulQuerier::parse_with_issues(text, ())
+    }
+}
+
+impl<'a, HandleIssue, Error> ParseWithIssues<&'a str, HandleIssue, Error> for ForgetfulQuerier<'a> {
+    fn parse_with_issues(text: &'a str, _: HandleIssue) -> PartialParseResult<Self, Error> {
+        text.pipe(ForgetfulQuerier::new)
+            .pipe(PartialParseResult::new_complete)
+    }
+}
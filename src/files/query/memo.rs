@@ -0,0 +1,144 @@
+use super::QueryMut;
+use crate::{
+    desc::utils::RawEntries,
+    files::{
+        field::{FieldName, ParsedField},
+        misc::{ReuseAdvice, True},
+    },
+    parse::{ParseWithIssues, PartialParse, PartialParseResult},
+};
+use core::convert::Infallible;
+use pipe_trait::Pipe;
+
+/// [Query](QueryMut) with a cache.
+#[derive(Debug, Clone)]
+pub struct MemoQuerier<'a> {
+    entries: RawEntries<'a>,
+    cache: Cache<'a>,
+}
+
+impl<'a> MemoQuerier<'a> {
+    /// Query the `text` with a cache.
+    pub fn new(text: &'a str) -> Self {
+        MemoQuerier {
+            entries: RawEntries::new(text),
+            cache: Cache::default(),
+        }
+    }
+
+    /// Private function for testing the internal cache.
+    #[doc(hidden)]
+    pub fn __has_cache(&self, field: FieldName) -> bool {
+        self.cache.get(&field).is_some()
+    }
+}
+
+impl<'a> QueryMut<'a> for MemoQuerier<'a> {
+    fn query_raw_text_mut(&mut self, field: ParsedField) -> Option<&'a str> {
+        if let Some(value) = self.cache.get(field.name()) {
+            return value;
+        }
+
+        for (raw_field, value) in self.entries.by_ref() {
+            let Ok(parsed_field) = raw_field.to_parsed::<FieldName>() else {
+                continue;
+            };
+            let value = if value.is_empty() { None } else { Some(value) };
+            self.cache.add(&parsed_field, value);
+            if parsed_field == field {
+                return value;
+            }
+        }
+
+        None
+    }
+}
+
+macro_rules! def_cache {
+    ($(
+        $(#[$attrs:meta])*
+        $field:ident $(,)? $(;)?
+    )*) => {
+        #[derive(Debug, Clone, Copy)]
+        enum CacheErr {
+            OccupiedWithNone,
+            Unoccupied,
+        }
+
+        #[derive(Debug, Clone)]
+        #[allow(non_snake_case, reason = "We don't access the field names directly, keep it simple.")]
+        struct Cache<'a> {$(
+            $(#[$attrs])*
+            $field: Result<&'a str, CacheErr>, // Result<&str, CacheErr> uses less memory than Option<Option<&str>>
+        )*}
+
+        impl<'a> Cache<'a> {
+            fn get(&self, field: &FieldName) -> Option<Option<&'a str>> {
+                match field {$(
+                    FieldName::$field => match self.$field {
+                        Ok(value) => Some(Some(value)),
+                        Err(CacheErr::OccupiedWithNone) => Some(None),
+                        Err(CacheErr::Unoccupied) => None,
+                    },
+                )*}
+            }
+
+            fn add(&mut self, field: &FieldName, value: Option<&'a str>) {
+                match (field, value) {$(
+                    (FieldName::$field, Some(value)) => self.$field = Ok(value),
+                    (FieldName::$field, None) => self.$field = Err(CacheErr::OccupiedWithNone),
+                )*}
+            }
+        }
+
+        impl<'a> Default for Cache<'a> {
+            fn default() -> Self {
+                Cache {$(
+                    $field: Err(CacheErr::Unoccupied),
+                )*}
+            }
+        }
+
+        #[test]
+        fn test_cache_fields() {$({
+            use pretty_assertions::assert_eq;
+            let field = &FieldName::$field;
+            let mut cache = Cache::default();
+            assert_eq!(cache.get(field), None);
+            cache.add(field, None);
+            assert_eq!(cache.get(field), Some(None));
+            cache.add(field, Some("foo"));
+            assert_eq!(cache.get(field), Some(Some("foo")));
+        })*}
+    };
+}
+
+def_cache!(Files Backup);
+
+impl ReuseAdvice for MemoQuerier<'_> {
+    /// [`MemoQuerier`] costs O(1) time to construct. Performing a lookup on it
+    /// costs O(n) the first time and O(1) after that.
+    ///
+    /// This struct is designed to be reused.
+    type ShouldReuse = True;
+}
+
+impl<'a> From<&'a str> for MemoQuerier<'a> {
+    fn from(value: &'a str) -> Self {
+        MemoQuerier::new(value)
+    }
+}
+
+impl<'a> PartialParse<&'a str> for MemoQuerier<'a> {
+    type Error = Infallible;
+    fn partial_parse(text: &'a str) -> PartialParseResult<Self, Self::Error> {
+        MemoQuerier::parse_with_issues(text, ())
+    }
+}
+
+impl<'a, HandleIssue, Error> ParseWithIssues<&'a str, HandleIssue, Error> for MemoQuerier<'a> {
+    fn parse_with_issues(text: &'a str, _: HandleIssue) -> PartialParseResult<Self, Error> {
+        text.pipe(MemoQuerier::new)
+            .pipe(PartialParseResult::new_complete)
+    }
+}
@@ -1,7 +1,14 @@
 #![cfg_attr(not(feature = "std"), no_std)]
+#[cfg(feature = "std")]
+pub mod db;
 pub mod desc;
+pub mod files;
+#[cfg(feature = "std")]
+pub mod lint;
 pub mod misc;
 pub mod parse;
+#[cfg(feature = "std")]
+pub mod resolve;
 pub mod srcinfo;
 pub mod value;
 
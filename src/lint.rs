@@ -0,0 +1,6 @@
+//! Lints of `.SRCINFO` files with structured diagnostics.
+
+mod diagnostic;
+mod srcinfo;
+
+pub use diagnostic::{Diagnostic, LintCode, LintIssue, Severity};
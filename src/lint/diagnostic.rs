@@ -0,0 +1,178 @@
+use crate::{
+    srcinfo::{ChecksumCountMismatch, FieldName, Section},
+    value::{
+        Architecture, FileName, FilePath, ParseReleaseError, Release, ValidateUpstreamVersionError,
+    },
+};
+use core::fmt::{self, Formatter};
+use derive_more::Display;
+use strum::{AsRefStr, IntoStaticStr};
+
+/// Severity of a [`Diagnostic`].
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The file is valid, but likely not what the packager intended.
+    #[display("warning")]
+    Warning,
+    /// `makepkg` would refuse to build the package.
+    #[display("error")]
+    Error,
+}
+
+/// Identifier of the kind of a [`Diagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)] // core traits
+#[derive(AsRefStr, strum::Display, IntoStaticStr)] // strum traits
+#[strum(serialize_all = "kebab-case")]
+pub enum LintCode {
+    /// See [`LintIssue::MissingField`].
+    MissingField,
+    /// See [`LintIssue::InvalidName`].
+    InvalidName,
+    /// See [`LintIssue::InvalidVersion`].
+    InvalidVersion,
+    /// See [`LintIssue::InvalidRelease`].
+    InvalidRelease,
+    /// See [`LintIssue::MissingArchitecture`].
+    MissingArchitecture,
+    /// See [`LintIssue::UnlistedArchitecture`].
+    UnlistedArchitecture,
+    /// See [`LintIssue::ChecksumCountMismatch`].
+    ChecksumCountMismatch,
+    /// See [`LintIssue::UnknownNoExtract`].
+    UnknownNoExtract,
+    /// See [`LintIssue::AbsoluteBackup`].
+    AbsoluteBackup,
+    /// See [`LintIssue::RedundantOverride`].
+    RedundantOverride,
+}
+
+impl LintCode {
+    /// Severity of the diagnostics of the code.
+    pub const fn severity(self) -> Severity {
+        match self {
+            LintCode::MissingField
+            | LintCode::InvalidName
+            | LintCode::InvalidVersion
+            | LintCode::InvalidRelease
+            | LintCode::MissingArchitecture
+            | LintCode::ChecksumCountMismatch
+            | LintCode::AbsoluteBackup => Severity::Error,
+            LintCode::UnlistedArchitecture
+            | LintCode::UnknownNoExtract
+            | LintCode::RedundantOverride => Severity::Warning,
+        }
+    }
+}
+
+/// Problem found by a lint.
+#[derive(Debug, Display, Clone)]
+pub enum LintIssue<'a> {
+    /// A required field (`pkgbase`, `pkgver`, or `pkgrel`) is absent.
+    #[display("{_0} is missing")]
+    MissingField(FieldName),
+    /// `pkgbase` or `pkgname` is not a valid package name.
+    #[display("{_0:?} is not a valid package name")]
+    InvalidName(&'a str),
+    /// `pkgver` contains characters which are not allowed.
+    #[display("Invalid pkgver: {_0}")]
+    InvalidVersion(ValidateUpstreamVersionError<'a>),
+    /// `pkgrel` is not a number or a number with a subrelease.
+    #[display("Invalid pkgrel {_0:?}: {_1}")]
+    InvalidRelease(Release<'a>, ParseReleaseError),
+    /// The section has no `arch`, neither its own nor inherited.
+    ///
+    /// A `pkgname` section which merely inherits the missing `arch` of the `pkgbase` section isn't reported.
+    #[display("arch is missing")]
+    MissingArchitecture,
+    /// A field has an architecture suffix which is not listed in the `arch` of the section.
+    #[display("{field}_{architecture} is defined but {architecture} is not listed in arch")]
+    UnlistedArchitecture {
+        /// Name of the field without the suffix.
+        field: FieldName,
+        /// The architecture suffix.
+        architecture: Architecture<'a>,
+    },
+    /// A checksum array has a different number of entries than the corresponding `source` array.
+    #[display("{_0}")]
+    ChecksumCountMismatch(ChecksumCountMismatch<'a>),
+    /// A `noextract` entry doesn't name the file of any `source`.
+    #[display("noextract entry {_0:?} is not the file name of any source")]
+    UnknownNoExtract(FileName<'a>),
+    /// A `backup` entry is an absolute path, while `makepkg` expects a path relative to the root.
+    #[display("backup entry {_0:?} should not start with a slash")]
+    AbsoluteBackup(FilePath<'a>),
+    /// A `pkgname` section redefines a field with the same values it would inherit from the `pkgbase` section.
+    #[display("{} duplicates the values of pkgbase", FieldDisplay(*_0, *_1))]
+    RedundantOverride(FieldName, Option<Architecture<'a>>),
+}
+
+impl LintIssue<'_> {
+    /// Get the [`LintCode`] of the issue.
+    pub const fn code(&self) -> LintCode {
+        match self {
+            LintIssue::MissingField(_) => LintCode::MissingField,
+            LintIssue::InvalidName(_) => LintCode::InvalidName,
+            LintIssue::InvalidVersion(_) => LintCode::InvalidVersion,
+            LintIssue::InvalidRelease(..) => LintCode::InvalidRelease,
+            LintIssue::MissingArchitecture => LintCode::MissingArchitecture,
+            LintIssue::UnlistedArchitecture { .. } => LintCode::UnlistedArchitecture,
+            LintIssue::ChecksumCountMismatch(_) => LintCode::ChecksumCountMismatch,
+            LintIssue::UnknownNoExtract(_) => LintCode::UnknownNoExtract,
+            LintIssue::AbsoluteBackup(_) => LintCode::AbsoluteBackup,
+            LintIssue::RedundantOverride(..) => LintCode::RedundantOverride,
+        }
+    }
+}
+
+/// Field name with an optional architecture suffix, displayed as it appears in `.SRCINFO`.
+struct FieldDisplay<'a>(FieldName, Option<Architecture<'a>>);
+
+impl fmt::Display for FieldDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let FieldDisplay(field_name, architecture) = self;
+        write!(f, "{field_name}")?;
+        if let Some(architecture) = architecture {
+            write!(f, "_{architecture}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Problem found in a section of a `.SRCINFO` file, an item of [`ParsedSrcinfo::lint`](crate::parse::ParsedSrcinfo::lint).
+///
+/// The [`Display`](core::fmt::Display) output is formatted as `severity[code] section: message`.
+#[derive(Debug, Clone)]
+pub struct Diagnostic<'a> {
+    /// Severity of the problem.
+    pub severity: Severity,
+    /// Identifier of the kind of the problem.
+    pub code: LintCode,
+    /// Section in which the problem was found.
+    pub section: Section<'a>,
+    /// Details of the problem.
+    pub issue: LintIssue<'a>,
+}
+
+impl<'a> Diagnostic<'a> {
+    /// Create a diagnostic with the code and the severity of the issue.
+    pub fn new(section: Section<'a>, issue: LintIssue<'a>) -> Self {
+        let code = issue.code();
+        Diagnostic {
+            severity: code.severity(),
+            code,
+            section,
+            issue,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}[{}] ", self.severity, self.code)?;
+        match self.section {
+            Section::Base => write!(f, "pkgbase")?,
+            Section::Derivative(name) => write!(f, "pkgname {name}")?,
+        }
+        write!(f, ": {}", self.issue)
+    }
+}
@@ -0,0 +1,232 @@
+use super::{Diagnostic, LintIssue};
+use crate::{
+    parse::ParsedSrcinfo,
+    srcinfo::{ChecksumType, FieldName, Query, QueryRawTextItem, Section, SourceChecksumPairs},
+    value::{Architecture, DependencyName, SourceFileName},
+};
+
+/// Fields which may have architecture suffixes, except checksums.
+const ARCH_FIELDS: &[FieldName] = &[
+    FieldName::Source,
+    FieldName::Dependencies,
+    FieldName::MakeDependencies,
+    FieldName::CheckDependencies,
+    FieldName::OptionalDependencies,
+    FieldName::Provides,
+    FieldName::Conflicts,
+    FieldName::Replaces,
+];
+
+/// Fields without architecture suffixes which a `pkgname` section may inherit from the `pkgbase` section.
+const NO_ARCH_INHERITABLE_FIELDS: &[FieldName] = &[
+    FieldName::Description,
+    FieldName::ChangeLog,
+    FieldName::InstallScript,
+    FieldName::Url,
+    FieldName::Architecture,
+    FieldName::Backup,
+    FieldName::Groups,
+    FieldName::License,
+    FieldName::NoExtract,
+    FieldName::Options,
+];
+
+impl<'a> ParsedSrcinfo<'a> {
+    /// Check the `.SRCINFO` for common problems.
+    ///
+    /// The diagnostics are grouped by lint in the order of the variants of [`LintIssue`], and are
+    /// in the order of appearance within each lint.
+    ///
+    /// ```
+    /// # use arch_pkg_text::{lint::{LintCode, Severity}, parse::ParsedSrcinfo};
+    /// # use pretty_assertions::assert_eq;
+    /// let srcinfo = ParsedSrcinfo::parse(concat!(
+    ///     "pkgbase = foo\n",
+    ///     "\tpkgver = 1.0.0\n",
+    ///     "\tpkgrel = 1\n",
+    ///     "\tarch = x86_64\n",
+    ///     "\tsource = foo.tar.gz\n",
+    ///     "\tsha256sums = SKIP\n",
+    ///     "\tbackup = /etc/foo.conf\n",
+    ///     "pkgname = foo\n",
+    /// ))
+    /// .try_into_complete()
+    /// .unwrap();
+    ///
+    /// let [diagnostic] = srcinfo.lint().try_into().unwrap();
+    /// assert_eq!(diagnostic.code, LintCode::AbsoluteBackup);
+    /// assert_eq!(diagnostic.severity, Severity::Error);
+    /// assert_eq!(
+    ///     diagnostic.to_string(),
+    ///     r#"error[absolute-backup] pkgbase: backup entry FilePath("/etc/foo.conf") should not start with a slash"#,
+    /// );
+    /// ```
+    pub fn lint(&self) -> Vec<Diagnostic<'a>> {
+        let mut diagnostics = Vec::new();
+        let mut report = |section: Section<'a>, issue: LintIssue<'a>| {
+            diagnostics.push(Diagnostic::new(section, issue));
+        };
+
+        // required fields of pkgbase
+        let required = [
+            (FieldName::Base, self.base.base_name().is_some()),
+            (FieldName::Version, self.base.version().is_some()),
+            (FieldName::Release, self.base.release().is_some()),
+        ];
+        for (field_name, present) in required {
+            if !present {
+                report(Section::Base, LintIssue::MissingField(field_name));
+            }
+        }
+
+        // pkgbase and pkgname
+        if let Some(base_name) = self.base.base_name()
+            && !is_valid_name(base_name.as_str())
+        {
+            report(Section::Base, LintIssue::InvalidName(base_name.as_str()));
+        }
+        for name in self.derivatives.keys() {
+            if !is_valid_name(name.as_str()) {
+                report(
+                    Section::Derivative(*name),
+                    LintIssue::InvalidName(name.as_str()),
+                );
+            }
+        }
+
+        // pkgver
+        if let Some(Err(error)) = self.base.version().map(|version| version.validate()) {
+            report(Section::Base, LintIssue::InvalidVersion(error));
+        }
+
+        // pkgrel
+        if let Some(release) = self.base.release()
+            && let Err(error) = release.parse()
+        {
+            report(Section::Base, LintIssue::InvalidRelease(release, error));
+        }
+
+        // arch
+        let base_has_architecture = !self.base.architecture().is_empty();
+        if !base_has_architecture {
+            report(Section::Base, LintIssue::MissingArchitecture);
+        }
+        for (package, derivative) in self.packages().zip(self.derivatives.values()) {
+            // a package which merely inherits the missing `arch` of pkgbase is already covered by pkgbase
+            let overrides =
+                base_has_architecture || derivative.is_cleared(FieldName::Architecture, None);
+            if overrides && package.architecture().next().is_none() {
+                report(
+                    Section::Derivative(package.name()),
+                    LintIssue::MissingArchitecture,
+                );
+            }
+        }
+
+        // architecture suffixes
+        let mut unlisted: Vec<(Section<'a>, FieldName, Architecture<'a>)> = Vec::new();
+        for field_name in ARCH_FIELDS.iter().copied().chain(checksum_fields()) {
+            for item in self.query_raw_text(field_name) {
+                let Some(architecture) = item.architecture else {
+                    continue;
+                };
+                let entry = (item.section, field_name, architecture);
+                if self.lists_architecture(item.section, architecture) || unlisted.contains(&entry)
+                {
+                    continue;
+                }
+                unlisted.push(entry);
+                report(
+                    item.section,
+                    LintIssue::UnlistedArchitecture {
+                        field: field_name,
+                        architecture,
+                    },
+                );
+            }
+        }
+
+        // checksums
+        for mismatch in SourceChecksumPairs::new(self).mismatches {
+            report(mismatch.section, LintIssue::ChecksumCountMismatch(mismatch));
+        }
+
+        // noextract
+        let source_file_names: Vec<SourceFileName<'a>> = self
+            .source()
+            .map(|item| item.value.parse().file_name())
+            .collect();
+        for item in self.no_extract() {
+            let known = source_file_names
+                .iter()
+                .any(|file_name| *file_name == item.value.as_str());
+            if !known {
+                report(item.section, LintIssue::UnknownNoExtract(item.value));
+            }
+        }
+
+        // backup
+        for item in self.backup() {
+            if item.value.starts_with('/') {
+                report(item.section, LintIssue::AbsoluteBackup(item.value));
+            }
+        }
+
+        // overrides which duplicate pkgbase
+        let inheritable_fields = NO_ARCH_INHERITABLE_FIELDS
+            .iter()
+            .chain(ARCH_FIELDS)
+            .copied()
+            .chain(checksum_fields());
+        for field_name in inheritable_fields {
+            let items: Vec<QueryRawTextItem<'a>> = self.query_raw_text(field_name).collect();
+            let mut locations: Vec<(Section<'a>, Option<Architecture<'a>>)> = Vec::new();
+            for item in &items {
+                let location = (item.section, item.architecture);
+                if item.section != Section::Base && !locations.contains(&location) {
+                    locations.push(location);
+                }
+            }
+            let values_at = |section: Section<'a>, architecture: Option<Architecture<'a>>| {
+                items
+                    .iter()
+                    .filter(move |item| {
+                        item.section == section && item.architecture == architecture
+                    })
+                    .map(|item| item.value)
+            };
+            for (section, architecture) in locations {
+                if values_at(section, architecture).eq(values_at(Section::Base, architecture)) {
+                    report(
+                        section,
+                        LintIssue::RedundantOverride(field_name, architecture),
+                    );
+                }
+            }
+        }
+
+        diagnostics
+    }
+
+    /// Whether an architecture is listed in the effective `arch` of a section.
+    fn lists_architecture(&self, section: Section<'a>, architecture: Architecture<'_>) -> bool {
+        let is_target = |listed: &Architecture<'_>| listed.as_str() == architecture.as_str();
+        match section {
+            Section::Base => self.base.architecture().iter().any(is_target),
+            Section::Derivative(name) => self
+                .package(name.as_str())
+                .is_some_and(|package| package.architecture().any(|item| is_target(&item.value))),
+        }
+    }
+}
+
+/// Names of the checksum fields.
+fn checksum_fields() -> impl Iterator<Item = FieldName> {
+    ChecksumType::all_types().map(|checksum_type| checksum_type.into_field_name())
+}
+
+/// Whether a string is a valid `pkgbase` or `pkgname`.
+fn is_valid_name(name: &str) -> bool {
+    let (name, rest) = DependencyName::parse(name);
+    !name.is_empty() && rest.is_empty()
+}
@@ -6,6 +6,9 @@ pub use partial::*;
 mod desc;
 pub use desc::*;
 
+#[cfg(all(feature = "serde", feature = "std"))]
+mod serde;
+
 #[cfg(feature = "std")]
 mod srcinfo;
 #[cfg(feature = "std")]
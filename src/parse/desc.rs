@@ -7,15 +7,32 @@ use derive_more::{Display, Error};
 use lines_inclusive::{LinesInclusive, LinesInclusiveIter};
 use pipe_trait::Pipe;
 
+#[cfg(feature = "std")]
+mod owned;
+#[cfg(feature = "serde")]
+mod serde;
+mod write;
+
+#[cfg(feature = "std")]
+pub use owned::OwnedDesc;
+
 macro_rules! def_struct {
     ($(
         $(#[$attrs:meta])*
         $field:ident $(,)? $(;)?
     )*) => {
+        /// All field names, in the order they are written.
+        #[cfg(feature = "std")]
+        const FIELD_NAMES: &[FieldName] = &[$(FieldName::$field,)*];
+
         /// Parsed data of a `desc` file text.
         ///
         /// Every function call in [`Query`] and [`QueryMut`] is constant time.
-        #[derive(Debug, Default, Clone, Copy)]
+        ///
+        /// Apart from parsing, a [`ParsedDesc`] can also be built from scratch with
+        /// [`with_raw_value`](ParsedDesc::with_raw_value) and written back to text with
+        /// [`write_to`](ParsedDesc::write_to) or [`Display`](core::fmt::Display).
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
         #[allow(non_snake_case, reason = "We don't access the field names directly, keep it simple.")]
         pub struct ParsedDesc<'a> {$(
             $(#[$attrs])*
@@ -30,24 +47,58 @@ macro_rules! def_struct {
                 )*}
             }
 
-            /// Add a raw value into the querier.
-            fn set_raw_value(&mut self, field_name: FieldName, raw_value: &'a str) {
+            /// Add, replace, or remove (with `None`) a raw value of the querier.
+            pub fn set_raw_value(&mut self, field_name: FieldName, raw_value: Option<&'a str>) {
                 match field_name {$(
-                    FieldName::$field => self.$field = Some(raw_value),
+                    FieldName::$field => self.$field = raw_value,
                 )*}
             }
+
+            /// List all fields which have values, in the order they are written.
+            fn raw_entries(&self) -> impl Iterator<Item = (FieldName, &'a str)> {
+                [$((FieldName::$field, self.$field),)*]
+                    .into_iter()
+                    .filter_map(|(field_name, raw_value)| raw_value.map(|raw_value| (field_name, raw_value)))
+            }
         }
     };
 }
 
+// The fields are listed in the same order as `repo-add` writes them.
+// Fields of the local database are placed where pacman writes them relative to the others.
 def_struct!(
     FileName Name Base Version Description Groups
     CompressedSize InstalledSize Md5Checksum Sha256Checksum
-    PgpSignature Url License Architecture BuildDate Packager
-    Dependencies CheckDependencies MakeDependencies OptionalDependencies
-    Provides Conflicts Replaces
+    PgpSignature Url License Architecture BuildDate InstallDate Packager
+    Size Reason Validation
+    Replaces Conflicts Provides
+    Dependencies OptionalDependencies MakeDependencies CheckDependencies
+    XData
 );
 
+impl<'a> ParsedDesc<'a> {
+    /// Return a copy of the querier with a raw value added or replaced.
+    ///
+    /// ```
+    /// # use arch_pkg_text::{desc::{FieldName, Query}, parse::ParsedDesc, value::Name};
+    /// # use pretty_assertions::assert_eq;
+    /// let desc = ParsedDesc::default()
+    ///     .with_raw_value(FieldName::Name, "foo")
+    ///     .with_raw_value(FieldName::Version, "1.2.3-1");
+    /// assert_eq!(desc.name(), Some(Name("foo")));
+    /// ```
+    pub fn with_raw_value(mut self, field_name: FieldName, raw_value: &'a str) -> Self {
+        self.set_raw_value(field_name, Some(raw_value));
+        self
+    }
+
+    /// Return a copy of the querier with a raw value removed.
+    pub fn without_raw_value(mut self, field_name: FieldName) -> Self {
+        self.set_raw_value(field_name, None);
+        self
+    }
+}
+
 /// Error type of [`ParsedDesc::parse`].
 #[derive(Debug, Display, Error, Clone, Copy)]
 pub enum DescParseError<'a> {
@@ -133,7 +184,7 @@ impl<'a> ParsedDesc<'a> {
             let value_end_offset = value_start_offset + value_length;
             if let Ok(field) = field.to_parsed::<FieldName>() {
                 let value = text[value_start_offset..value_end_offset].trim();
-                parsed.set_raw_value(*field.name(), value);
+                parsed.set_raw_value(*field.name(), Some(value));
             } else {
                 return_or!(DescParseIssue::UnknownField(field), ())
             }
@@ -0,0 +1,120 @@
+use super::{FIELD_NAMES, ParsedDesc};
+use crate::desc::{
+    FieldName, ParsedField, Query, QueryMut,
+    misc::{ReuseAdvice, True},
+};
+use core::{
+    fmt::{self, Display, Formatter, Write},
+    ops::Range,
+};
+
+/// Owned counterpart of [`ParsedDesc`].
+///
+/// It holds the text of a `desc` file together with the location of each value in the text,
+/// so it can be stored without keeping the original text alive.
+///
+/// Queries are implemented for `&OwnedDesc` and are as cheap as queries on [`ParsedDesc`].
+///
+/// ```
+/// # use arch_pkg_text::{desc::Query, parse::{OwnedDesc, ParsedDesc}, value::Name};
+/// # use pretty_assertions::assert_eq;
+/// let owned = {
+///     let text = String::from("%NAME%\nfoo\n\n%VERSION%\n1.2.3-1\n");
+///     ParsedDesc::parse(&text).map(OwnedDesc::from).unwrap()
+/// };
+/// assert_eq!((&owned).name(), Some(Name("foo")));
+/// assert_eq!(owned.as_parsed().name(), Some(Name("foo")));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedDesc {
+    text: String,
+    /// Location of the value of each field in the text, indexed by [`FieldName`].
+    ranges: [Option<Range<usize>>; FIELD_NAMES.len()],
+}
+
+impl Default for OwnedDesc {
+    fn default() -> Self {
+        OwnedDesc {
+            text: String::new(),
+            ranges: [const { None }; FIELD_NAMES.len()],
+        }
+    }
+}
+
+impl OwnedDesc {
+    /// Get the text of the `desc` file, as written by [`ParsedDesc::write_to`].
+    pub fn text(&self) -> &'_ str {
+        &self.text
+    }
+
+    /// Get the raw value of a field.
+    fn get_raw_value(&self, field_name: FieldName) -> Option<&'_ str> {
+        self.ranges[field_name as usize]
+            .clone()
+            .map(|range| &self.text[range])
+    }
+
+    /// Borrow the data as a [`ParsedDesc`].
+    pub fn as_parsed(&self) -> ParsedDesc<'_> {
+        let mut parsed = ParsedDesc::default();
+        for field_name in FIELD_NAMES {
+            parsed.set_raw_value(*field_name, self.get_raw_value(*field_name));
+        }
+        parsed
+    }
+}
+
+impl From<&ParsedDesc<'_>> for OwnedDesc {
+    fn from(parsed: &ParsedDesc<'_>) -> Self {
+        let mut text = String::new();
+        let mut ranges = [const { None }; FIELD_NAMES.len()];
+        for (field_name, raw_value) in parsed.raw_entries() {
+            writeln!(text, "{}", ParsedField::new(field_name))
+                .expect("writing to a string never fails");
+            let start = text.len();
+            text.push_str(raw_value);
+            ranges[field_name as usize] = Some(start..text.len());
+            text.push_str("\n\n");
+        }
+        OwnedDesc { text, ranges }
+    }
+}
+
+impl From<ParsedDesc<'_>> for OwnedDesc {
+    fn from(parsed: ParsedDesc<'_>) -> Self {
+        OwnedDesc::from(&parsed)
+    }
+}
+
+impl<'a> From<&'a OwnedDesc> for ParsedDesc<'a> {
+    fn from(owned: &'a OwnedDesc) -> Self {
+        owned.as_parsed()
+    }
+}
+
+impl<'a> Query<'a> for &'a OwnedDesc {
+    fn query_raw_text(&self, field: ParsedField) -> Option<&'a str> {
+        self.get_raw_value(*field.name())
+    }
+}
+
+impl<'a> QueryMut<'a> for &'a OwnedDesc {
+    fn query_raw_text_mut(&mut self, field: ParsedField) -> Option<&'a str> {
+        self.query_raw_text(field)
+    }
+}
+
+impl ReuseAdvice for OwnedDesc {
+    /// [`OwnedDesc`] costs O(n) time to construct (n being text length).
+    /// Performing a lookup on it costs O(1) time.
+    ///
+    /// This struct is designed to be reused.
+    type ShouldReuse = True;
+}
+
+/// Write the text of the `desc` file, see [`OwnedDesc::text`].
+impl Display for OwnedDesc {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
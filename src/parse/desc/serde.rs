@@ -0,0 +1,102 @@
+use super::ParsedDesc;
+use crate::desc::FieldName;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+#[cfg(feature = "std")]
+use super::OwnedDesc;
+#[cfg(feature = "std")]
+use crate::parse::serde::RawValue;
+#[cfg(feature = "std")]
+use indexmap::IndexMap;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Deserializer};
+
+/// Whether the value of a field is a list of lines.
+fn is_list(field_name: FieldName) -> bool {
+    match field_name {
+        FieldName::Groups
+        | FieldName::License
+        | FieldName::Architecture
+        | FieldName::Validation
+        | FieldName::Replaces
+        | FieldName::Conflicts
+        | FieldName::Provides
+        | FieldName::Dependencies
+        | FieldName::OptionalDependencies
+        | FieldName::MakeDependencies
+        | FieldName::CheckDependencies
+        | FieldName::XData => true,
+        FieldName::FileName
+        | FieldName::Name
+        | FieldName::Base
+        | FieldName::Version
+        | FieldName::Description
+        | FieldName::CompressedSize
+        | FieldName::InstalledSize
+        | FieldName::Md5Checksum
+        | FieldName::Sha256Checksum
+        | FieldName::PgpSignature
+        | FieldName::Url
+        | FieldName::BuildDate
+        | FieldName::InstallDate
+        | FieldName::Packager
+        | FieldName::Size
+        | FieldName::Reason => false,
+    }
+}
+
+/// Serialize a list value as an array of lines.
+struct Lines<'a>(&'a str);
+
+impl Serialize for Lines<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.split('\n'))
+    }
+}
+
+/// Serialize the fields as a map from field names (such as `NAME` and `DEPENDS`) to values.
+///
+/// Values of list fields (such as `DEPENDS`) are serialized as arrays of strings,
+/// values of other fields are serialized as strings.
+impl Serialize for ParsedDesc<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.raw_entries().count()))?;
+        for (field_name, raw_value) in self.raw_entries() {
+            let key: &str = field_name.into();
+            if is_list(field_name) {
+                map.serialize_entry(key, &Lines(raw_value))?;
+            } else {
+                map.serialize_entry(key, raw_value)?;
+            }
+        }
+        map.end()
+    }
+}
+
+/// Serialize in the same format as [`ParsedDesc`].
+#[cfg(feature = "std")]
+impl Serialize for OwnedDesc {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_parsed().serialize(serializer)
+    }
+}
+
+/// Deserialize from the format of the serialized [`ParsedDesc`], unknown fields are ignored.
+#[cfg(feature = "std")]
+impl<'de> Deserialize<'de> for OwnedDesc {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values: Vec<(FieldName, String)> =
+            IndexMap::<String, RawValue>::deserialize(deserializer)?
+                .into_iter()
+                .filter_map(|(key, value)| {
+                    let field_name = key.parse::<FieldName>().ok()?;
+                    Some((field_name, value.values().collect::<Vec<_>>().join("\n")))
+                })
+                .collect();
+        let mut parsed = ParsedDesc::default();
+        for (field_name, value) in &values {
+            parsed.set_raw_value(*field_name, Some(value));
+        }
+        Ok(OwnedDesc::from(parsed))
+    }
+}
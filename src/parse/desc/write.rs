@@ -0,0 +1,40 @@
+use super::ParsedDesc;
+use crate::desc::ParsedField;
+use core::fmt::{self, Display, Formatter, Write};
+
+impl ParsedDesc<'_> {
+    /// Write the fields in the `desc` format.
+    ///
+    /// Fields are written in the same order as `repo-add` writes them, each followed by
+    /// its value and a blank line. Fields without values are skipped.
+    ///
+    /// Parsing the output with [`ParsedDesc::parse`] returns the same values as long as
+    /// none of the values contains a line that looks like a field (such as `%NAME%`).
+    ///
+    /// ```
+    /// # use arch_pkg_text::{desc::FieldName, parse::ParsedDesc};
+    /// # use pretty_assertions::assert_eq;
+    /// let desc = ParsedDesc::default()
+    ///     .with_raw_value(FieldName::Version, "1.2.3-1")
+    ///     .with_raw_value(FieldName::Name, "foo");
+    /// let mut text = String::new();
+    /// desc.write_to(&mut text).unwrap();
+    /// assert_eq!(text, "%NAME%\nfoo\n\n%VERSION%\n1.2.3-1\n\n");
+    /// assert_eq!(ParsedDesc::parse(&text).unwrap(), desc);
+    /// ```
+    pub fn write_to<Writer: Write + ?Sized>(&self, writer: &mut Writer) -> fmt::Result {
+        for (field_name, raw_value) in self.raw_entries() {
+            writeln!(writer, "{}", ParsedField::new(field_name))?;
+            writeln!(writer, "{raw_value}")?;
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Write the fields in the `desc` format, see [`ParsedDesc::write_to`].
+impl Display for ParsedDesc<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
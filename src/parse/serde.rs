@@ -0,0 +1,22 @@
+use serde::Deserialize;
+
+/// Value of a field in the serialized form of a parsed document.
+///
+/// Single values are serialized as strings, lists are serialized as arrays of strings.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum RawValue {
+    Single(String),
+    List(Vec<String>),
+}
+
+impl RawValue {
+    /// List the values as string slices.
+    pub(crate) fn values(&self) -> impl Iterator<Item = &'_ str> {
+        let (single, list) = match self {
+            RawValue::Single(value) => (Some(value.as_str()), None),
+            RawValue::List(values) => (None, Some(values.iter().map(String::as_str))),
+        };
+        single.into_iter().chain(list.into_iter().flatten())
+    }
+}
@@ -1,10 +1,16 @@
 mod checksums;
 mod data;
+mod owned;
+mod package;
+#[cfg(feature = "serde")]
+mod serde;
+mod write;
 
 use super::{ParseWithIssues, PartialParse, PartialParseResult};
 use crate::{
     srcinfo::{
-        Field, FieldName, ParsedField, RawField, Section,
+        ClearedFields, ClearedFieldsMut, Field, FieldName, ParsedField, QueryClearedItem, RawField,
+        Section,
         misc::{ReuseAdvice, True},
         utils::{non_blank_trimmed_lines, parse_line},
     },
@@ -14,6 +20,9 @@ use derive_more::{Display, Error};
 use indexmap::IndexMap;
 use pipe_trait::Pipe;
 
+pub use owned::OwnedSrcinfo;
+pub use package::ParsedSrcinfoPackage;
+
 pub use data::{
     ParsedSrcinfoBaseSection, ParsedSrcinfoBaseUniqueFieldDuplicationError,
     ParsedSrcinfoDerivativeSection, ParsedSrcinfoDerivativeUniqueFieldDuplicationError,
@@ -28,6 +37,11 @@ pub struct ParsedSrcinfo<'a> {
     pub derivatives: IndexMap<value::Name<'a>, ParsedSrcinfoDerivativeSection<'a>>,
 }
 
+/// Field name, architecture suffix, and raw value of an entry in a section.
+///
+/// The value is `None` for a [cleared field](ParsedSrcinfoDerivativeSection::cleared_fields).
+type RawEntry<'a> = (FieldName, Option<value::Architecture<'a>>, Option<&'a str>);
+
 /// Write cursor of the sections in [`ParsedSrcinfo`].
 enum ParsedSrcinfoSectionMut<'a, 'r> {
     /// Write to the `pkgbase` section.
@@ -71,8 +85,17 @@ fn unknown_field_from_parsed(field: ParsedField<&str>) -> Result<(), AddFailure<
 
 impl<'a> ParsedSrcinfoSectionMut<'a, '_> {
     /// Add an entry to a `pkgbase` or `pkgname` section.
+    ///
+    /// Empty values are [recorded as cleared fields](ParsedSrcinfoDerivativeSection::cleared_fields)
+    /// in `pkgname` sections and ignored elsewhere.
     fn add(&mut self, field: ParsedField<&'a str>, value: &'a str) -> Result<(), AddFailure<'a>> {
         match self {
+            _ if value.is_empty() && *field.name() == FieldName::Name => Ok(()),
+            ParsedSrcinfoSectionMut::Base(_) if value.is_empty() => Ok(()),
+            ParsedSrcinfoSectionMut::Derivative(section) if value.is_empty() => {
+                section.clear(field);
+                Ok(())
+            }
             ParsedSrcinfoSectionMut::Base(section) => section.add(field, value),
             ParsedSrcinfoSectionMut::Derivative(section) => section.add(field, value),
         }
@@ -228,9 +251,6 @@ impl<'a> ParsedSrcinfo<'a> {
             let Ok(field) = field.to_parsed::<FieldName, &str>() else {
                 return_or_continue!(SrcinfoParseIssue::UnknownField(field));
             };
-            if value.is_empty() {
-                continue;
-            }
             match section_mut.add(field, value) {
                 Ok(()) => {}
                 Err(AddFailure::MeetHeader(name)) => {
@@ -276,6 +296,26 @@ impl<'a> TryFrom<&'a str> for ParsedSrcinfo<'a> {
     }
 }
 
+impl<'a> ClearedFields<'a> for ParsedSrcinfo<'a> {
+    fn cleared_fields(&self) -> impl Iterator<Item = QueryClearedItem<'a>> {
+        self.derivatives.iter().flat_map(|(name, derivative)| {
+            derivative
+                .cleared_fields()
+                .iter()
+                .map(|(field_name, architecture)| {
+                    (*field_name, Section::Derivative(*name), *architecture)
+                })
+                .map(QueryClearedItem::from_tuple3)
+        })
+    }
+}
+
+impl<'a> ClearedFieldsMut<'a> for ParsedSrcinfo<'a> {
+    fn cleared_fields_mut(&mut self) -> impl Iterator<Item = QueryClearedItem<'a>> {
+        self.cleared_fields()
+    }
+}
+
 impl ReuseAdvice for ParsedSrcinfo<'_> {
     /// [`ParsedSrcinfo`] costs O(n) time to construct (n being text length).
     /// Performing a lookup on it costs O(1) time.
@@ -108,6 +108,7 @@ macro_rules! def_impl {
 }
 
 def_impl! {
+    Crc32(SkipOrCrc32) <- crc32_checksums;
     Md5(SkipOrHex128) <- md5_checksums;
     Sha1(SkipOrHex160) <- sha1_checksums;
     Sha224(SkipOrHex224) <- sha224_checksums;
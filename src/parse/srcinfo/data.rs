@@ -1,5 +1,6 @@
 use super::{
-    AddFailure, ParsedSrcinfo, ParsedSrcinfoDerivativeSectionEntryMut, unknown_field_from_parsed,
+    AddFailure, ParsedSrcinfo, ParsedSrcinfoDerivativeSectionEntryMut, RawEntry,
+    unknown_field_from_parsed,
 };
 use crate::{
     srcinfo::{FieldName, ParsedField, Query, QueryItem, QueryMut, QueryRawTextItem, Section},
@@ -199,6 +200,15 @@ macro_rules! def_struct {
             > { self.$shared_multi_arch_name() })*
         }
 
+        /// Whether a field may only be defined once per section.
+        #[cfg(feature = "serde")]
+        pub(super) fn is_unique_field(field_name: FieldName) -> bool {
+            matches!(
+                field_name,
+                FieldName::Name $(| FieldName::$base_single_field)* $(| FieldName::$shared_single_field)*
+            )
+        }
+
         /// Parsed information of a `pkgbase` section.
         #[derive(Debug, Default, Clone)]
         pub struct ParsedSrcinfoBaseSection<'a> {
@@ -275,6 +285,34 @@ macro_rules! def_struct {
                 }
             }
 
+            /// Insert an entry listed by [`raw_entries`](ParsedSrcinfoBaseSection::raw_entries) of a valid section.
+            ///
+            /// Unlike [`add`](ParsedSrcinfoBaseSection::add), nothing is validated: a unique field is overwritten,
+            /// and an entry which doesn't belong to the section is ignored.
+            pub(super) fn insert_raw_entry(&mut self, (field_name, architecture, value): RawEntry<'a>) {
+                let Some(value) = value else {
+                    return;
+                };
+                match (field_name, architecture) {
+                    $((FieldName::$base_single_field, None) => {
+                        self.$base_single_name = Some(value::$base_single_type::new(value));
+                    })*
+                    $((FieldName::$base_multi_field, None) => {
+                        self.$base_multi_name.push(value::$base_multi_type::new(value));
+                    })*
+                    $((FieldName::$shared_single_field, None) => {
+                        self.$shared_single_name = Some(value::$shared_single_type::new(value));
+                    })*
+                    $((FieldName::$shared_multi_no_arch_field, None) => {
+                        self.$shared_multi_no_arch_name.push(value::$shared_multi_no_arch_type::new(value));
+                    })*
+                    $((FieldName::$shared_multi_arch_field, architecture) => {
+                        self.$shared_multi_arch_name.push((value::$shared_multi_arch_type::new(value), architecture));
+                    })*
+                    _ => {}
+                }
+            }
+
             /// Shrink all internal containers' capacities to fit.
             pub fn shrink_to_fit(&mut self) {
                 $(self.$base_multi_name.shrink_to_fit();)*
@@ -282,6 +320,27 @@ macro_rules! def_struct {
                 $(self.$shared_multi_arch_name.shrink_to_fit();)*
             }
 
+            /// List all entries of the section, grouped by field.
+            pub(super) fn raw_entries(&self) -> Vec<RawEntry<'a>> {
+                let mut entries = Vec::new();
+                $(if let Some(value) = self.$base_single_name {
+                    entries.push((FieldName::$base_single_field, None, Some(value.as_str())));
+                })*
+                $(entries.extend(
+                    self.$base_multi_name.iter().map(|value| (FieldName::$base_multi_field, None, Some(value.as_str()))),
+                );)*
+                $(if let Some(value) = self.$shared_single_name {
+                    entries.push((FieldName::$shared_single_field, None, Some(value.as_str())));
+                })*
+                $(entries.extend(
+                    self.$shared_multi_no_arch_name.iter().map(|value| (FieldName::$shared_multi_no_arch_field, None, Some(value.as_str()))),
+                );)*
+                $(entries.extend(
+                    self.$shared_multi_arch_name.iter().map(|(value, architecture)| (FieldName::$shared_multi_arch_field, *architecture, Some(value.as_str()))),
+                );)*
+                entries
+            }
+
             $(pub fn $base_single_name(&self) -> Option<value::$base_single_type<'a>> { self.$base_single_name })*
             $(pub fn $base_multi_name(&self) -> &'_ [value::$base_multi_type<'a>] { &self.$base_multi_name })*
             $(pub fn $shared_single_name(&self) -> Option<value::$shared_single_type<'a>> { self.$shared_single_name })*
@@ -298,6 +357,7 @@ macro_rules! def_struct {
             $($shared_single_name: Option<value::$shared_single_type<'a>>,)*
             $($shared_multi_no_arch_name: Vec<value::$shared_multi_no_arch_type<'a>>,)*
             $($shared_multi_arch_name: Vec<(value::$shared_multi_arch_type<'a>, Option<value::Architecture<'a>>)>,)*
+            cleared: Vec<(FieldName, Option<value::Architecture<'a>>)>,
         }
 
         /// Error that occurs when a pkgname section of `.SRCINFO` defines a unique field more than once.
@@ -308,10 +368,71 @@ macro_rules! def_struct {
         )*}
 
         impl<'a> ParsedSrcinfoDerivativeSection<'a> {
+            /// Insert an entry listed by [`raw_entries`](ParsedSrcinfoDerivativeSection::raw_entries) of a valid section.
+            ///
+            /// Unlike [`ParsedSrcinfoDerivativeSectionEntryMut::add`], nothing is validated: a unique field is overwritten,
+            /// and an entry which doesn't belong to the section is ignored.
+            pub(super) fn insert_raw_entry(&mut self, (field_name, architecture, value): RawEntry<'a>) {
+                let Some(value) = value else {
+                    self.cleared.push((field_name, architecture));
+                    return;
+                };
+                match (field_name, architecture) {
+                    $((FieldName::$shared_single_field, None) => {
+                        self.$shared_single_name = Some(value::$shared_single_type::new(value));
+                    })*
+                    $((FieldName::$shared_multi_no_arch_field, None) => {
+                        self.$shared_multi_no_arch_name.push(value::$shared_multi_no_arch_type::new(value));
+                    })*
+                    $((FieldName::$shared_multi_arch_field, architecture) => {
+                        self.$shared_multi_arch_name.push((value::$shared_multi_arch_type::new(value), architecture));
+                    })*
+                    _ => {}
+                }
+            }
+
             /// Shrink all internal containers' capacities to fit.
             pub fn shrink_to_fit(&mut self) {
                 $(self.$shared_multi_no_arch_name.shrink_to_fit();)*
                 $(self.$shared_multi_arch_name.shrink_to_fit();)*
+                self.cleared.shrink_to_fit();
+            }
+
+            /// List all entries of the section, grouped by field.
+            ///
+            /// [Cleared fields](ParsedSrcinfoDerivativeSection::cleared_fields) are listed last without values.
+            pub(super) fn raw_entries(&self) -> Vec<RawEntry<'a>> {
+                let mut entries = Vec::new();
+                $(if let Some(value) = self.$shared_single_name {
+                    entries.push((FieldName::$shared_single_field, None, Some(value.as_str())));
+                })*
+                $(entries.extend(
+                    self.$shared_multi_no_arch_name.iter().map(|value| (FieldName::$shared_multi_no_arch_field, None, Some(value.as_str()))),
+                );)*
+                $(entries.extend(
+                    self.$shared_multi_arch_name.iter().map(|(value, architecture)| (FieldName::$shared_multi_arch_field, *architecture, Some(value.as_str()))),
+                );)*
+                entries.extend(
+                    self.cleared.iter().map(|(field_name, architecture)| (*field_name, *architecture, None)),
+                );
+                entries
+            }
+
+            /// List fields which were explicitly assigned empty values (such as `depends =`) in the order of their appearances.
+            ///
+            /// An empty assignment means the package doesn't inherit the field (with the same architecture suffix)
+            /// from the `pkgbase` section.
+            pub fn cleared_fields(&self) -> &'_ [(FieldName, Option<value::Architecture<'a>>)] {
+                &self.cleared
+            }
+
+            /// Check whether a field with an architecture suffix was explicitly assigned an empty value.
+            pub fn is_cleared(&self, field_name: FieldName, architecture: Option<value::Architecture<'_>>) -> bool {
+                self.cleared.iter().any(|(cleared_name, cleared_architecture)| {
+                    *cleared_name == field_name
+                        && cleared_architecture.map(|architecture| architecture.as_str())
+                            == architecture.map(|architecture| architecture.as_str())
+                })
             }
 
             $(pub fn $shared_single_name(&self) -> Option<value::$shared_single_type<'a>> { self.$shared_single_name })*
@@ -363,6 +484,22 @@ macro_rules! def_struct {
                     })*
                 }
             }
+
+            /// Record an empty assignment of a field, which clears the value inherited from the `pkgbase` section.
+            ///
+            /// Empty assignments of fields which can't be inherited are ignored.
+            pub(super) fn clear(&mut self, field: ParsedField<&'a str>) {
+                let architecture = field.architecture().copied().map(value::Architecture);
+                let inheritable = match (field.name(), architecture) {
+                    $((FieldName::$shared_single_field, None) => true,)*
+                    $((FieldName::$shared_multi_no_arch_field, None) => true,)*
+                    $((FieldName::$shared_multi_arch_field, _) => true,)*
+                    _ => false,
+                };
+                if inheritable && !self.data.is_cleared(*field.name(), architecture) {
+                    self.data.cleared.push((*field.name(), architecture));
+                }
+            }
         }
     };
 }
@@ -405,6 +542,7 @@ def_struct! {
         replaces, replaces_mut = Replaces -> Dependency;
 
         /* CHECKSUMS */
+        crc32_checksums, crc32_checksums_mut = Crc32Checksums -> SkipOrCrc32;
         md5_checksums, md5_checksums_mut = Md5Checksums -> SkipOrHex128;
         sha1_checksums, sha1_checksums_mut = Sha1Checksums -> SkipOrHex160;
         sha224_checksums, sha224_checksums_mut = Sha224Checksums -> SkipOrHex224;
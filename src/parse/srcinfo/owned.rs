@@ -0,0 +1,261 @@
+use super::{ParsedSrcinfo, ParsedSrcinfoBaseSection, ParsedSrcinfoDerivativeSection, write::Line};
+use crate::{
+    srcinfo::{
+        ChecksumValue, Checksums, ChecksumsMut, ClearedFields, ClearedFieldsMut, FieldName, Query,
+        QueryChecksumItem, QueryClearedItem, QueryMut, QueryRawTextItem, Section,
+        misc::{ReuseAdvice, True},
+    },
+    value,
+};
+use core::{
+    fmt::{self, Display, Formatter, Write},
+    ops::Range,
+};
+
+/// Owned counterpart of [`ParsedSrcinfo`].
+///
+/// It holds the text of a `.SRCINFO` file together with the location of each value in the text,
+/// so it can be stored without keeping the original text alive.
+///
+/// Queries are implemented for `&OwnedSrcinfo`.
+///
+/// ```
+/// # use arch_pkg_text::{parse::{OwnedSrcinfo, ParsedSrcinfo}, srcinfo::Query, value::Name};
+/// # use pretty_assertions::assert_eq;
+/// let owned = {
+///     let text = String::from("pkgbase = foo\npkgver = 1.2.3\npkgname = foo\npkgname = bar\n");
+///     ParsedSrcinfo::parse(&text).try_into_complete().map(OwnedSrcinfo::from).unwrap()
+/// };
+/// let names: Vec<_> = (&owned).derivative_names().collect();
+/// assert_eq!(names, [Name("foo"), Name("bar")]);
+/// assert_eq!(owned.as_parsed().derivatives.len(), 2);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct OwnedSrcinfo {
+    text: String,
+    derivatives: Vec<Range<usize>>,
+    entries: Vec<OwnedSrcinfoEntry>,
+}
+
+/// Location of an entry in the text of an [`OwnedSrcinfo`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct OwnedSrcinfoEntry {
+    /// Index of the `pkgname` section in which the entry resides, `None` means the `pkgbase` section.
+    derivative: Option<usize>,
+    field_name: FieldName,
+    architecture: Option<Range<usize>>,
+    /// Location of the value, `None` means the field was [cleared](ClearedFields::cleared_fields).
+    value: Option<Range<usize>>,
+}
+
+/// Append a string to `text` and return its location.
+fn push_str(text: &mut String, value: &str) -> Range<usize> {
+    let start = text.len();
+    text.push_str(value);
+    start..text.len()
+}
+
+impl OwnedSrcinfo {
+    /// Get the text of the `.SRCINFO` file, as written by [`ParsedSrcinfo::write_to`].
+    pub fn text(&self) -> &'_ str {
+        &self.text
+    }
+
+    /// Get the name of a `pkgname` section.
+    fn derivative_name(&self, index: usize) -> value::Name<'_> {
+        value::Name(&self.text[self.derivatives[index].clone()])
+    }
+
+    /// Get the section of an entry.
+    fn section(&self, entry: &OwnedSrcinfoEntry) -> Section<'_> {
+        match entry.derivative {
+            None => Section::Base,
+            Some(index) => Section::Derivative(self.derivative_name(index)),
+        }
+    }
+
+    /// Get the architecture suffix of an entry.
+    fn architecture(&self, entry: &OwnedSrcinfoEntry) -> Option<value::Architecture<'_>> {
+        entry
+            .architecture
+            .clone()
+            .map(|range| value::Architecture(&self.text[range]))
+    }
+
+    /// Get the value of an entry, `None` means the field was cleared.
+    fn value(&self, entry: &OwnedSrcinfoEntry) -> Option<&'_ str> {
+        entry.value.clone().map(|range| &self.text[range])
+    }
+
+    /// Borrow the data as a [`ParsedSrcinfo`].
+    pub fn as_parsed(&self) -> ParsedSrcinfo<'_> {
+        let mut base = ParsedSrcinfoBaseSection::default();
+        let mut derivatives =
+            vec![ParsedSrcinfoDerivativeSection::default(); self.derivatives.len()];
+        for entry in &self.entries {
+            let raw_entry = (
+                entry.field_name,
+                self.architecture(entry),
+                self.value(entry),
+            );
+            match entry.derivative {
+                None => base.insert_raw_entry(raw_entry),
+                Some(index) => derivatives[index].insert_raw_entry(raw_entry),
+            }
+        }
+        let derivatives = (0..self.derivatives.len())
+            .map(|index| self.derivative_name(index))
+            .zip(derivatives)
+            .collect();
+        ParsedSrcinfo { base, derivatives }
+    }
+}
+
+impl From<&ParsedSrcinfo<'_>> for OwnedSrcinfo {
+    fn from(parsed: &ParsedSrcinfo<'_>) -> Self {
+        let mut text = String::new();
+        let mut derivatives = Vec::new();
+        let mut entries = Vec::new();
+        for line in parsed.lines() {
+            match line {
+                Line::Header(field_name, name) => {
+                    write!(text, "{field_name} = ").expect("writing to a string never fails");
+                    let name = push_str(&mut text, name);
+                    text.push('\n');
+                    match field_name {
+                        FieldName::Name => derivatives.push(name),
+                        _ => entries.push(OwnedSrcinfoEntry {
+                            derivative: None,
+                            field_name,
+                            architecture: None,
+                            value: Some(name),
+                        }),
+                    }
+                }
+                Line::Entry((field_name, architecture, value)) => {
+                    write!(text, "\t{field_name}").expect("writing to a string never fails");
+                    let architecture = architecture.map(|architecture| {
+                        text.push('_');
+                        push_str(&mut text, architecture.as_str())
+                    });
+                    text.push_str(" = ");
+                    let value = value.map(|value| push_str(&mut text, value));
+                    text.push('\n');
+                    entries.push(OwnedSrcinfoEntry {
+                        derivative: derivatives.len().checked_sub(1),
+                        field_name,
+                        architecture,
+                        value,
+                    });
+                }
+                Line::End => text.push('\n'),
+            }
+        }
+
+        OwnedSrcinfo {
+            text,
+            derivatives,
+            entries,
+        }
+    }
+}
+
+impl From<ParsedSrcinfo<'_>> for OwnedSrcinfo {
+    fn from(parsed: ParsedSrcinfo<'_>) -> Self {
+        OwnedSrcinfo::from(&parsed)
+    }
+}
+
+impl<'a> From<&'a OwnedSrcinfo> for ParsedSrcinfo<'a> {
+    fn from(owned: &'a OwnedSrcinfo) -> Self {
+        owned.as_parsed()
+    }
+}
+
+impl<'a> Query<'a> for &'a OwnedSrcinfo {
+    fn query_raw_text(&self, field_name: FieldName) -> impl Iterator<Item = QueryRawTextItem<'a>> {
+        let owned: &'a OwnedSrcinfo = self;
+        let names = (0..owned.derivatives.len())
+            .filter(move |_| field_name == FieldName::Name)
+            .map(|index| owned.derivative_name(index))
+            .map(|name| (name.as_str(), Section::Derivative(name), None));
+        let values = owned
+            .entries
+            .iter()
+            .filter(move |entry| entry.field_name == field_name)
+            .filter_map(|entry| {
+                let value = owned.value(entry)?;
+                Some((value, owned.section(entry), owned.architecture(entry)))
+            });
+        names.chain(values).map(QueryRawTextItem::from_tuple3)
+    }
+}
+
+impl<'a> QueryMut<'a> for &'a OwnedSrcinfo {
+    fn query_raw_text_mut(
+        &mut self,
+        field_name: FieldName,
+    ) -> impl Iterator<Item = QueryRawTextItem<'a>> {
+        self.query_raw_text(field_name)
+    }
+}
+
+impl<'a> Checksums<'a> for &'a OwnedSrcinfo {
+    fn checksums(&self) -> impl Iterator<Item = QueryChecksumItem<'a>> {
+        let owned: &'a OwnedSrcinfo = self;
+        owned
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                let value = owned.value(entry)?;
+                ChecksumValue::try_from_field_name(entry.field_name, value)
+                    .map(|value| (value, owned.section(entry), owned.architecture(entry)))
+            })
+            .map(QueryChecksumItem::from_tuple3)
+    }
+}
+
+impl<'a> ChecksumsMut<'a> for &'a OwnedSrcinfo {
+    fn checksums_mut(&mut self) -> impl Iterator<Item = QueryChecksumItem<'a>> {
+        self.checksums()
+    }
+}
+
+impl<'a> ClearedFields<'a> for &'a OwnedSrcinfo {
+    fn cleared_fields(&self) -> impl Iterator<Item = QueryClearedItem<'a>> {
+        let owned: &'a OwnedSrcinfo = self;
+        owned
+            .entries
+            .iter()
+            .filter(|entry| entry.value.is_none())
+            .map(|entry| {
+                (
+                    entry.field_name,
+                    owned.section(entry),
+                    owned.architecture(entry),
+                )
+            })
+            .map(QueryClearedItem::from_tuple3)
+    }
+}
+
+impl<'a> ClearedFieldsMut<'a> for &'a OwnedSrcinfo {
+    fn cleared_fields_mut(&mut self) -> impl Iterator<Item = QueryClearedItem<'a>> {
+        self.cleared_fields()
+    }
+}
+
+impl ReuseAdvice for OwnedSrcinfo {
+    /// [`OwnedSrcinfo`] costs O(n) time to construct (n being text length).
+    /// Performing a lookup on it costs O(n) time (n being the number of entries).
+    ///
+    /// This struct is designed to be reused.
+    type ShouldReuse = True;
+}
+
+/// Write the text of the `.SRCINFO` file, see [`OwnedSrcinfo::text`].
+impl Display for OwnedSrcinfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
@@ -0,0 +1,187 @@
+use super::{ParsedSrcinfo, ParsedSrcinfoBaseSection, ParsedSrcinfoDerivativeSection};
+use crate::{
+    srcinfo::{
+        ChecksumType, ChecksumValue, Checksums, ChecksumsMut, ClearedFields, ClearedFieldsMut,
+        FieldName, Query, QueryChecksumItem, QueryClearedItem, QueryItem, QueryMut,
+        QueryRawTextItem, Section,
+        misc::{ReuseAdvice, True},
+    },
+    value,
+};
+
+/// Effective information of a single package (`pkgname`) of a [`ParsedSrcinfo`].
+///
+/// The view follows the inheritance rules of `makepkg`: a field which is defined in the `pkgname`
+/// section replaces the same field of the `pkgbase` section instead of adding to it, while a field
+/// which is not defined in the `pkgname` section is inherited from the `pkgbase` section. Fields with
+/// different architecture suffixes are different variables, so overriding `depends` doesn't affect
+/// `depends_x86_64`, and vice versa. An empty assignment (such as `depends =`) in the `pkgname` section
+/// [clears](ParsedSrcinfoDerivativeSection::cleared_fields) the inherited value.
+///
+/// Fields which only belong to the `pkgbase` section (such as `pkgver` and `pkgrel`) are reported under
+/// [`Section::Base`], every other field is reported under the [`Section::Derivative`] of the package.
+///
+/// ```
+/// # use arch_pkg_text::{parse::ParsedSrcinfo, srcinfo::Query, value::{Dependency, Description}};
+/// # use pretty_assertions::assert_eq;
+/// let srcinfo = ParsedSrcinfo::parse(concat!(
+///     "pkgbase = foo\n",
+///     "\tpkgdesc = Foo\n",
+///     "\tpkgver = 1.2.3\n",
+///     "\tpkgrel = 1\n",
+///     "\tdepends = glibc\n",
+///     "\tdepends = gcc-libs\n",
+///     "pkgname = foo\n",
+///     "pkgname = libfoo\n",
+///     "\tpkgdesc = Library of foo\n",
+///     "\tdepends = glibc\n",
+/// ))
+/// .try_into_complete()
+/// .unwrap();
+///
+/// let foo = srcinfo.package("foo").unwrap();
+/// assert_eq!(foo.version().unwrap().as_str(), "1.2.3");
+/// let description: Vec<_> = foo.description().map(|item| item.value).collect();
+/// assert_eq!(description, [Description("Foo")]);
+/// let dependencies: Vec<_> = foo.dependencies().map(|item| item.value).collect();
+/// assert_eq!(dependencies, [Dependency("glibc"), Dependency("gcc-libs")]);
+///
+/// let libfoo = srcinfo.package("libfoo").unwrap();
+/// assert_eq!(libfoo.version().unwrap().as_str(), "1.2.3");
+/// let description: Vec<_> = libfoo.description().map(|item| item.value).collect();
+/// assert_eq!(description, [Description("Library of foo")]);
+/// let dependencies: Vec<_> = libfoo.dependencies().map(|item| item.value).collect();
+/// assert_eq!(dependencies, [Dependency("glibc")]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ParsedSrcinfoPackage<'r, 'a> {
+    name: value::Name<'a>,
+    srcinfo: &'r ParsedSrcinfo<'a>,
+    derivative: &'r ParsedSrcinfoDerivativeSection<'a>,
+}
+
+impl<'a> ParsedSrcinfo<'a> {
+    /// Get the effective view of a package, return `None` if there is no such `pkgname`.
+    pub fn package(&self, name: &str) -> Option<ParsedSrcinfoPackage<'_, 'a>> {
+        self.packages()
+            .find(|package| package.name.as_str() == name)
+    }
+
+    /// List the effective views of all packages in the order of their `pkgname` sections.
+    pub fn packages(&self) -> impl Iterator<Item = ParsedSrcinfoPackage<'_, 'a>> {
+        self.derivatives
+            .iter()
+            .map(|(name, derivative)| ParsedSrcinfoPackage {
+                name: *name,
+                srcinfo: self,
+                derivative,
+            })
+    }
+}
+
+impl<'r, 'a> ParsedSrcinfoPackage<'r, 'a> {
+    /// Get the name of the package.
+    pub fn name(&self) -> value::Name<'a> {
+        self.name
+    }
+
+    /// Get the `pkgbase` section.
+    pub fn base_section(&self) -> &'r ParsedSrcinfoBaseSection<'a> {
+        &self.srcinfo.base
+    }
+
+    /// Get the `pkgname` section of the package.
+    pub fn derivative_section(&self) -> &'r ParsedSrcinfoDerivativeSection<'a> {
+        self.derivative
+    }
+}
+
+impl<'a> Query<'a> for ParsedSrcinfoPackage<'_, 'a> {
+    fn query_raw_text(&self, field_name: FieldName) -> impl Iterator<Item = QueryRawTextItem<'a>> {
+        let own_section = Section::Derivative(self.name);
+
+        if field_name == FieldName::Name {
+            let item = QueryItem::from_tuple3((self.name.as_str(), own_section, None));
+            return vec![item].into_iter();
+        }
+
+        let items = self
+            .srcinfo
+            .query_raw_text(field_name)
+            .filter(|item| item.section == Section::Base || item.section == own_section);
+
+        if !field_name.is_inheritable() {
+            return items
+                .filter(|item| item.section == Section::Base)
+                .collect::<Vec<_>>()
+                .into_iter();
+        }
+
+        let (own, inherited): (Vec<_>, Vec<_>) =
+            items.partition(|item| item.section == own_section);
+        let overridden = |architecture: Option<value::Architecture>| {
+            self.derivative.is_cleared(field_name, architecture)
+                || own.iter().any(|item| item.architecture == architecture)
+        };
+        let inherited: Vec<_> = inherited
+            .into_iter()
+            .filter(|item| !overridden(item.architecture))
+            .map(|item| QueryItem::from_tuple3((item.value, own_section, item.architecture)))
+            .collect();
+        inherited
+            .into_iter()
+            .chain(own)
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+impl<'a> QueryMut<'a> for ParsedSrcinfoPackage<'_, 'a> {
+    fn query_raw_text_mut(
+        &mut self,
+        field_name: FieldName,
+    ) -> impl Iterator<Item = QueryRawTextItem<'a>> {
+        self.query_raw_text(field_name)
+    }
+}
+
+impl<'a> Checksums<'a> for ParsedSrcinfoPackage<'_, 'a> {
+    fn checksums(&self) -> impl Iterator<Item = QueryChecksumItem<'a>> {
+        ChecksumType::all_types().flat_map(|checksum_type| {
+            let checksum_type = *checksum_type;
+            self.query_raw_text(checksum_type.into_field_name())
+                .map(move |item| item.map(move |value| ChecksumValue::new(checksum_type, value)))
+        })
+    }
+}
+
+impl<'a> ChecksumsMut<'a> for ParsedSrcinfoPackage<'_, 'a> {
+    fn checksums_mut(&mut self) -> impl Iterator<Item = QueryChecksumItem<'a>> {
+        self.checksums()
+    }
+}
+
+impl<'a> ClearedFields<'a> for ParsedSrcinfoPackage<'_, 'a> {
+    fn cleared_fields(&self) -> impl Iterator<Item = QueryClearedItem<'a>> {
+        let section = Section::Derivative(self.name);
+        self.derivative
+            .cleared_fields()
+            .iter()
+            .map(move |(field_name, architecture)| (*field_name, section, *architecture))
+            .map(QueryClearedItem::from_tuple3)
+    }
+}
+
+impl<'a> ClearedFieldsMut<'a> for ParsedSrcinfoPackage<'_, 'a> {
+    fn cleared_fields_mut(&mut self) -> impl Iterator<Item = QueryClearedItem<'a>> {
+        self.cleared_fields()
+    }
+}
+
+impl ReuseAdvice for ParsedSrcinfoPackage<'_, '_> {
+    /// [`ParsedSrcinfoPackage`] costs O(1) time to construct.
+    /// Performing a lookup on it costs O(n) time (n being the number of entries of the queried field).
+    ///
+    /// This struct is designed to be reused.
+    type ShouldReuse = True;
+}
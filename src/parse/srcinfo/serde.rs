@@ -0,0 +1,140 @@
+use super::{
+    AddFailure, OwnedSrcinfo, ParsedSrcinfo, ParsedSrcinfoBaseSection,
+    ParsedSrcinfoDerivativeSection, RawEntry, data::is_unique_field,
+};
+use crate::{
+    parse::serde::RawValue,
+    srcinfo::{FieldName, RawField, Section},
+    value,
+};
+use indexmap::IndexMap;
+use serde::{
+    Deserialize, Deserializer,
+    de::Error,
+    ser::{Serialize, SerializeMap, SerializeStruct, Serializer},
+};
+
+/// Key of a serialized section entry, such as `depends` or `depends_x86_64`.
+struct Key<'a>(FieldName, Option<value::Architecture<'a>>);
+
+impl Serialize for Key<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Key(field_name, None) => serializer.serialize_str(field_name.into()),
+            Key(field_name, Some(architecture)) => {
+                serializer.collect_str(&format_args!("{field_name}_{architecture}"))
+            }
+        }
+    }
+}
+
+/// Serialize the entries of a section as a map from keys to values.
+///
+/// Entries of the same field and architecture are grouped together in the order of their first appearances.
+fn serialize_entries<S: Serializer>(
+    entries: Vec<RawEntry<'_>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut groups: Vec<(Key, Vec<&str>)> = Vec::new();
+    for (field_name, architecture, value) in entries {
+        match groups
+            .iter_mut()
+            .find(|(Key(name, arch), _)| *name == field_name && *arch == architecture)
+        {
+            Some((_, values)) => values.push(value.unwrap_or_default()),
+            None => groups.push((
+                Key(field_name, architecture),
+                vec![value.unwrap_or_default()],
+            )),
+        }
+    }
+
+    let mut map = serializer.serialize_map(Some(groups.len()))?;
+    for (key, values) in &groups {
+        match values.as_slice() {
+            [value] if is_unique_field(key.0) => map.serialize_entry(key, value)?,
+            values => map.serialize_entry(key, values)?,
+        }
+    }
+    map.end()
+}
+
+/// Serialize the section as a map from keys (such as `pkgver`, `depends`, and `depends_x86_64`) to values.
+///
+/// Values of fields which may only be defined once (such as `pkgver`) are serialized as strings,
+/// values of other fields are serialized as arrays of strings.
+impl Serialize for ParsedSrcinfoBaseSection<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_entries(self.raw_entries(), serializer)
+    }
+}
+
+/// Serialize the section in the same format as [`ParsedSrcinfoBaseSection`].
+impl Serialize for ParsedSrcinfoDerivativeSection<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_entries(self.raw_entries(), serializer)
+    }
+}
+
+/// Serialize as a struct of `base` and `derivatives`, the latter being a map from `pkgname` to section.
+impl Serialize for ParsedSrcinfo<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut srcinfo = serializer.serialize_struct("ParsedSrcinfo", 2)?;
+        srcinfo.serialize_field("base", &self.base)?;
+        srcinfo.serialize_field("derivatives", &self.derivatives)?;
+        srcinfo.end()
+    }
+}
+
+/// Serialize in the same format as [`ParsedSrcinfo`].
+impl Serialize for OwnedSrcinfo {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_parsed().serialize(serializer)
+    }
+}
+
+/// Intermediate form of the serialized [`ParsedSrcinfo`].
+#[derive(Deserialize)]
+struct RawSrcinfo {
+    #[serde(default)]
+    base: IndexMap<String, RawValue>,
+    #[serde(default)]
+    derivatives: IndexMap<String, IndexMap<String, RawValue>>,
+}
+
+/// Add the entries of a serialized section to a [`ParsedSrcinfo`].
+fn add_entries<'a, E: Error>(
+    parsed: &mut ParsedSrcinfo<'a>,
+    section: Section<'a>,
+    entries: &'a IndexMap<String, RawValue>,
+) -> Result<(), E> {
+    let mut section_mut = parsed.get_or_insert(section);
+    for (key, values) in entries {
+        let Ok(field) = RawField::parse_raw(key).to_parsed::<FieldName, &str>() else {
+            continue; // unknown fields are ignored
+        };
+        for value in values.values() {
+            match section_mut.add(field, value) {
+                Ok(()) => {}
+                Err(AddFailure::MeetHeader(_)) => {
+                    return Err(E::custom("Field pkgname is not allowed inside a section"));
+                }
+                Err(AddFailure::Issue(issue)) => issue.ignore_unknown_field().map_err(E::custom)?,
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Deserialize from the format of the serialized [`ParsedSrcinfo`], unknown fields are ignored.
+impl<'de> Deserialize<'de> for OwnedSrcinfo {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = RawSrcinfo::deserialize(deserializer)?;
+        let mut parsed = ParsedSrcinfo::default();
+        add_entries(&mut parsed, Section::Base, &raw.base)?;
+        for (name, entries) in &raw.derivatives {
+            add_entries(&mut parsed, Section::Derivative(value::Name(name)), entries)?;
+        }
+        Ok(OwnedSrcinfo::from(&parsed))
+    }
+}
@@ -0,0 +1,201 @@
+use super::{ParsedSrcinfo, RawEntry};
+use crate::{srcinfo::FieldName, value};
+use core::fmt::{self, Display, Formatter, Write};
+
+impl ParsedSrcinfo<'_> {
+    /// Write the sections in the `.SRCINFO` format.
+    ///
+    /// The output mimics `makepkg --printsrcinfo`: the `pkgbase` section comes first, followed by
+    /// the `pkgname` sections in their original order. Within each section, the fields are written
+    /// in the same order as `makepkg` writes them, fields with architecture suffixes come after all
+    /// fields without ones, and every section is terminated by a blank line.
+    ///
+    /// ```
+    /// # use arch_pkg_text::parse::ParsedSrcinfo;
+    /// # use pretty_assertions::assert_eq;
+    /// let srcinfo = ParsedSrcinfo::parse(concat!(
+    ///     "pkgbase = foo\n",
+    ///     "depends_x86_64 = bar\n",
+    ///     "arch = x86_64\n",
+    ///     "pkgver = 1.2.3\n",
+    ///     "depends = baz\n",
+    ///     "pkgname = foo\n",
+    /// ))
+    /// .try_into_complete()
+    /// .unwrap();
+    /// assert_eq!(
+    ///     srcinfo.to_string(),
+    ///     concat!(
+    ///         "pkgbase = foo\n",
+    ///         "\tpkgver = 1.2.3\n",
+    ///         "\tarch = x86_64\n",
+    ///         "\tdepends = baz\n",
+    ///         "\tdepends_x86_64 = bar\n",
+    ///         "\n",
+    ///         "pkgname = foo\n",
+    ///         "\n",
+    ///     ),
+    /// );
+    /// ```
+    pub fn write_to<Writer: Write + ?Sized>(&self, writer: &mut Writer) -> fmt::Result {
+        for line in self.lines() {
+            match line {
+                Line::Header(field_name, name) => writeln!(writer, "{field_name} = {name}")?,
+                Line::Entry((field_name, None, value)) => {
+                    writeln!(writer, "\t{field_name} = {}", value.unwrap_or_default())?;
+                }
+                Line::Entry((field_name, Some(architecture), value)) => {
+                    let value = value.unwrap_or_default();
+                    writeln!(writer, "\t{field_name}_{architecture} = {value}")?;
+                }
+                Line::End => writeln!(writer)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ParsedSrcinfo<'a> {
+    /// List the lines of the text written by [`ParsedSrcinfo::write_to`] in order.
+    pub(super) fn lines(&self) -> Vec<Line<'a>> {
+        let mut lines = Vec::new();
+
+        if let Some(base_name) = self.base.base_name() {
+            lines.push(Line::Header(FieldName::Base, base_name.as_str()));
+        }
+        let entries = self
+            .base
+            .raw_entries()
+            .into_iter()
+            .filter(|(field_name, _, _)| *field_name != FieldName::Base)
+            .collect();
+        push_entries(&mut lines, entries, self.base.architecture());
+
+        for (name, section) in &self.derivatives {
+            lines.push(Line::Header(FieldName::Name, name.as_str()));
+            let architectures = match section.architecture() {
+                [] => self.base.architecture(),
+                architectures => architectures,
+            };
+            push_entries(&mut lines, section.raw_entries(), architectures);
+        }
+
+        lines
+    }
+}
+
+/// Write the `.SRCINFO` text, see [`ParsedSrcinfo::write_to`].
+impl Display for ParsedSrcinfo<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+/// Line of the `.SRCINFO` text, see [`ParsedSrcinfo::lines`].
+pub(super) enum Line<'a> {
+    /// `pkgbase = <name>` or `pkgname = <name>`, which starts a section.
+    Header(FieldName, &'a str),
+    /// Entry of the current section.
+    Entry(RawEntry<'a>),
+    /// Blank line which terminates a section.
+    End,
+}
+
+/// Sort the entries of a section and push them followed by a blank line.
+///
+/// Entries with architecture suffixes are grouped by architecture in the order of `architectures`,
+/// architectures which don't appear in `architectures` follow in the order of their appearances.
+fn push_entries<'a>(
+    lines: &mut Vec<Line<'a>>,
+    mut entries: Vec<RawEntry<'a>>,
+    architectures: &[value::Architecture<'a>],
+) {
+    let mut architecture_order = architectures.to_vec();
+    for (_, architecture, _) in &entries {
+        if let Some(architecture) = architecture
+            && !architecture_order.contains(architecture)
+        {
+            architecture_order.push(*architecture);
+        }
+    }
+
+    let architecture_rank = |architecture: Option<value::Architecture>| match architecture {
+        None => 0,
+        Some(architecture) => architecture_order
+            .iter()
+            .position(|item| *item == architecture)
+            .map_or(usize::MAX, |index| index + 1),
+    };
+    entries.sort_by_key(|(field_name, architecture, _)| {
+        let field_rank = match architecture {
+            None => field_rank(*field_name),
+            Some(_) => arch_field_rank(*field_name),
+        };
+        (architecture_rank(*architecture), field_rank)
+    });
+
+    lines.extend(entries.into_iter().map(Line::Entry));
+    lines.push(Line::End);
+}
+
+/// Position of a field within a section in the output of `makepkg --printsrcinfo`.
+fn field_rank(field_name: FieldName) -> u8 {
+    match field_name {
+        FieldName::Base | FieldName::Name => 0,
+        FieldName::Description => 1,
+        FieldName::Version => 2,
+        FieldName::Release => 3,
+        FieldName::Epoch => 4,
+        FieldName::Url => 5,
+        FieldName::InstallScript => 6,
+        FieldName::ChangeLog => 7,
+        FieldName::Architecture => 8,
+        FieldName::Groups => 9,
+        FieldName::License => 10,
+        FieldName::CheckDependencies => 11,
+        FieldName::MakeDependencies => 12,
+        FieldName::Dependencies => 13,
+        FieldName::OptionalDependencies => 14,
+        FieldName::Provides => 15,
+        FieldName::Conflicts => 16,
+        FieldName::Replaces => 17,
+        FieldName::NoExtract => 18,
+        FieldName::Options => 19,
+        FieldName::Backup => 20,
+        FieldName::Source => 21,
+        FieldName::ValidPgpKeys => 22,
+        FieldName::Crc32Checksums => 23,
+        FieldName::Md5Checksums => 24,
+        FieldName::Sha1Checksums => 25,
+        FieldName::Sha224Checksums => 26,
+        FieldName::Sha256Checksums => 27,
+        FieldName::Sha384Checksums => 28,
+        FieldName::Sha512Checksums => 29,
+        FieldName::Blake2bChecksums => 30,
+    }
+}
+
+/// Position of an architecture-suffixed field within its architecture block in the output of `makepkg --printsrcinfo`.
+///
+/// Only fields which may have architecture suffixes are ranked, others are placed last.
+fn arch_field_rank(field_name: FieldName) -> u8 {
+    match field_name {
+        FieldName::Source => 0,
+        FieldName::Provides => 1,
+        FieldName::Conflicts => 2,
+        FieldName::Dependencies => 3,
+        FieldName::Replaces => 4,
+        FieldName::OptionalDependencies => 5,
+        FieldName::MakeDependencies => 6,
+        FieldName::CheckDependencies => 7,
+        FieldName::Crc32Checksums => 8,
+        FieldName::Md5Checksums => 9,
+        FieldName::Sha1Checksums => 10,
+        FieldName::Sha224Checksums => 11,
+        FieldName::Sha256Checksums => 12,
+        FieldName::Sha384Checksums => 13,
+        FieldName::Sha512Checksums => 14,
+        FieldName::Blake2bChecksums => 15,
+        _ => u8::MAX,
+    }
+}
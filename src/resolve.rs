@@ -0,0 +1,9 @@
+//! Dependency resolution among packages described by `desc` files and build ordering of `.SRCINFO` files.
+
+mod build_plan;
+mod provider_index;
+mod resolver;
+
+pub use build_plan::{BuildPlan, BuildPlanner};
+pub use provider_index::{Provider, ProviderIndex};
+pub use resolver::{Resolution, ResolveIssue, ResolveReason, ResolvedPackage, Resolver};
@@ -0,0 +1,278 @@
+use crate::{
+    parse::ParsedSrcinfo,
+    srcinfo::Query,
+    value::{Architecture, Base, Dependency, Name, Version},
+};
+
+/// Planner of the order in which many `pkgbase`s should be built.
+///
+/// The planner builds a graph of `pkgbase`s: a `pkgbase` depends on another when one of its
+/// `depends`, `makedepends`, or `checkdepends` (without architecture suffixes or with the suffix of
+/// the target architecture) is satisfied by a `pkgname` or a `provides` of the other. Dependencies
+/// which are not satisfied by any of the `pkgbase`s are assumed to be available elsewhere.
+///
+/// `.SRCINFO`s without `pkgbase` are ignored.
+///
+/// ```
+/// # use arch_pkg_text::{parse::ParsedSrcinfo, resolve::BuildPlanner, value::{Architecture, Base}};
+/// # use pretty_assertions::assert_eq;
+/// let srcinfos = [
+///     "pkgbase = app\n\tpkgver = 1.0\n\tpkgrel = 1\n\tmakedepends = libfoo-dev\n\tdepends = glibc\npkgname = app\n",
+///     "pkgbase = foo\n\tpkgver = 2.0\n\tpkgrel = 1\npkgname = libfoo\npkgname = libfoo-dev\n",
+///     "pkgbase = bar\n\tpkgver = 3.0\n\tpkgrel = 1\npkgname = bar\n",
+/// ]
+/// .map(|text| ParsedSrcinfo::parse(text).try_into_complete().unwrap());
+/// let plan = BuildPlanner::new(Architecture("x86_64"), &srcinfos).plan();
+/// assert_eq!(plan.batches, [vec![Base("foo"), Base("bar")], vec![Base("app")]]);
+/// assert!(plan.cycles.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct BuildPlanner<'r, 'a> {
+    architecture: Architecture<'a>,
+    srcinfos: Vec<&'r ParsedSrcinfo<'a>>,
+}
+
+/// Result of [`BuildPlanner::plan`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BuildPlan<'a> {
+    /// Groups of `pkgbase`s in build order, the `pkgbase`s of the same group can be built in parallel.
+    pub batches: Vec<Vec<Base<'a>>>,
+    /// Groups of `pkgbase`s which depend on each other, these can't be built in any order.
+    pub cycles: Vec<Vec<Base<'a>>>,
+    /// `pkgbase`s which are not part of any cycle but depend on one.
+    pub blocked: Vec<Base<'a>>,
+}
+
+/// Package which a `pkgbase` produces.
+struct BuiltPackage<'a> {
+    name: Name<'a>,
+    provisions: Vec<Dependency<'a>>,
+}
+
+impl<'r, 'a> BuildPlanner<'r, 'a> {
+    /// Create a planner of `.SRCINFO`s for a target architecture.
+    pub fn new<Srcinfos>(architecture: Architecture<'a>, srcinfos: Srcinfos) -> Self
+    where
+        Srcinfos: IntoIterator<Item = &'r ParsedSrcinfo<'a>>,
+    {
+        let srcinfos = srcinfos
+            .into_iter()
+            .filter(|srcinfo| srcinfo.base.base_name().is_some())
+            .collect();
+        BuildPlanner {
+            architecture,
+            srcinfos,
+        }
+    }
+
+    /// Whether an architecture suffix applies to the target architecture.
+    fn applies(&self, architecture: Option<Architecture<'_>>) -> bool {
+        architecture.is_none_or(|architecture| architecture.as_str() == self.architecture.as_str())
+    }
+
+    /// Build-time dependencies of a `pkgbase`.
+    fn build_dependencies(&self, srcinfo: &ParsedSrcinfo<'a>) -> Vec<Dependency<'a>> {
+        let base = &srcinfo.base;
+        base.dependencies()
+            .iter()
+            .chain(base.make_dependencies())
+            .chain(base.check_dependencies())
+            .filter(|(_, architecture)| self.applies(*architecture))
+            .map(|(dependency, _)| *dependency)
+            .collect()
+    }
+
+    /// Packages which a `pkgbase` produces with their provisions for the target architecture.
+    fn built_packages(&self, srcinfo: &ParsedSrcinfo<'a>) -> Vec<BuiltPackage<'a>> {
+        srcinfo
+            .packages()
+            .map(|package| {
+                let provisions = package
+                    .provides()
+                    .filter(|item| self.applies(item.architecture))
+                    .map(|item| item.value)
+                    .collect();
+                BuiltPackage {
+                    name: package.name(),
+                    provisions,
+                }
+            })
+            .collect()
+    }
+
+    /// Plan the build order.
+    pub fn plan(&self) -> BuildPlan<'a> {
+        let bases: Vec<Base<'a>> = self
+            .srcinfos
+            .iter()
+            .filter_map(|srcinfo| srcinfo.base.base_name())
+            .collect();
+        let versions: Vec<String> = self
+            .srcinfos
+            .iter()
+            .map(|srcinfo| full_version(srcinfo))
+            .collect();
+        let packages: Vec<_> = self
+            .srcinfos
+            .iter()
+            .map(|srcinfo| self.built_packages(srcinfo))
+            .collect();
+
+        // find the pkgbase which satisfies a dependency, exact names are preferred over provisions
+        let find_provider = |dependency: &Dependency<'_>| {
+            let by_name = packages.iter().enumerate().find(|(index, packages)| {
+                let version = Version(&versions[*index]);
+                packages
+                    .iter()
+                    .any(|package| dependency.satisfied_by_package(package.name, Some(version)))
+            });
+            let by_provision = || {
+                packages.iter().enumerate().find(|(_, packages)| {
+                    packages.iter().any(|package| {
+                        package
+                            .provisions
+                            .iter()
+                            .any(|provision| dependency.satisfied_by_provision(provision))
+                    })
+                })
+            };
+            by_name.or_else(by_provision).map(|(index, _)| index)
+        };
+
+        let dependencies: Vec<Vec<usize>> = self
+            .srcinfos
+            .iter()
+            .enumerate()
+            .map(|(index, srcinfo)| {
+                let mut dependencies: Vec<usize> = self
+                    .build_dependencies(srcinfo)
+                    .iter()
+                    .filter_map(find_provider)
+                    .filter(|provider| *provider != index)
+                    .collect();
+                dependencies.sort_unstable();
+                dependencies.dedup();
+                dependencies
+            })
+            .collect();
+
+        let mut plan = BuildPlan::default();
+        let mut remaining: Vec<usize> = dependencies.iter().map(Vec::len).collect();
+        let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); bases.len()];
+        for (dependent, dependencies) in dependencies.iter().enumerate() {
+            for dependency in dependencies {
+                dependents[*dependency].push(dependent);
+            }
+        }
+
+        let mut built = vec![false; bases.len()];
+        let mut batch: Vec<usize> = (0..bases.len())
+            .filter(|index| remaining[*index] == 0)
+            .collect();
+        while !batch.is_empty() {
+            let mut next = Vec::new();
+            for &index in &batch {
+                built[index] = true;
+                for &dependent in &dependents[index] {
+                    remaining[dependent] -= 1;
+                    if remaining[dependent] == 0 {
+                        next.push(dependent);
+                    }
+                }
+            }
+            next.sort_unstable();
+            plan.batches
+                .push(batch.iter().map(|index| bases[*index]).collect());
+            batch = next;
+        }
+
+        let cycles = find_cycles(&dependencies, &built);
+        let in_cycle = |index: usize| cycles.iter().any(|cycle| cycle.contains(&index));
+        plan.blocked = (0..bases.len())
+            .filter(|index| !built[*index] && !in_cycle(*index))
+            .map(|index| bases[index])
+            .collect();
+        plan.cycles = cycles
+            .into_iter()
+            .map(|cycle| cycle.into_iter().map(|index| bases[index]).collect())
+            .collect();
+        plan
+    }
+}
+
+/// Full version (`epoch:pkgver-pkgrel`) of the packages of a `pkgbase`.
+fn full_version(srcinfo: &ParsedSrcinfo<'_>) -> String {
+    let base = &srcinfo.base;
+    let version = base.version().map_or("", |version| version.as_str());
+    let release = base.release().map_or("", |release| release.as_str());
+    match base.epoch() {
+        Some(epoch) => format!("{epoch}:{version}-{release}"),
+        None => format!("{version}-{release}"),
+    }
+}
+
+/// Find the strongly connected components with more than one node among the nodes which weren't built.
+///
+/// Each component is sorted, and the components are sorted by their first nodes.
+fn find_cycles(dependencies: &[Vec<usize>], built: &[bool]) -> Vec<Vec<usize>> {
+    let size = dependencies.len();
+    let mut counter = 0;
+    let mut indices: Vec<Option<usize>> = vec![None; size];
+    let mut low_links = vec![0; size];
+    let mut stack = Vec::new();
+    let mut on_stack = vec![false; size];
+    let mut components: Vec<Vec<usize>> = Vec::new();
+
+    for root in 0..size {
+        if built[root] || indices[root].is_some() {
+            continue;
+        }
+        let mut call_stack = vec![(root, 0)];
+        while let Some((node, next)) = call_stack.last_mut() {
+            let node = *node;
+            if indices[node].is_none() {
+                indices[node] = Some(counter);
+                low_links[node] = counter;
+                counter += 1;
+                stack.push(node);
+                on_stack[node] = true;
+            }
+            if let Some(&child) = dependencies[node].get(*next) {
+                *next += 1;
+                if built[child] {
+                    continue;
+                }
+                match indices[child] {
+                    None => call_stack.push((child, 0)),
+                    Some(index) if on_stack[child] => {
+                        low_links[node] = low_links[node].min(index);
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            call_stack.pop();
+            if let Some(&(parent, _)) = call_stack.last() {
+                low_links[parent] = low_links[parent].min(low_links[node]);
+            }
+            if Some(low_links[node]) == indices[node] {
+                let mut component = Vec::new();
+                while let Some(member) = stack.pop() {
+                    on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                if component.len() > 1 {
+                    component.sort_unstable();
+                    components.push(component);
+                }
+            }
+        }
+    }
+
+    components.sort_unstable_by_key(|component| component[0]);
+    components
+}
@@ -0,0 +1,197 @@
+use crate::{
+    desc::Query,
+    value::{Dependency, Name, Version},
+};
+use std::collections::{HashMap, HashSet};
+
+/// Index of packages by their names and their provisions (`PROVIDES`).
+///
+/// The index answers which packages can satisfy a [`Dependency`], be it a package name, a virtual name
+/// (such as `sh`), or a [soname](Dependency::soname) (such as `libGL.so=1-64`).
+///
+/// ```
+/// # use arch_pkg_text::{parse::ParsedDesc, resolve::ProviderIndex, value::{Dependency, Name}};
+/// # use pretty_assertions::assert_eq;
+/// let bash = ParsedDesc::parse("%NAME%\nbash\n\n%VERSION%\n5.2.037-1\n\n%PROVIDES%\nsh\n").unwrap();
+/// let dash = ParsedDesc::parse("%NAME%\ndash\n\n%VERSION%\n0.5.12-1\n\n%PROVIDES%\nsh\n").unwrap();
+/// let sh = ParsedDesc::parse("%NAME%\nsh\n\n%VERSION%\n1.0-1\n").unwrap();
+/// let index = ProviderIndex::new([bash, dash, sh]);
+/// let providers: Vec<_> = index
+///     .providers(Dependency("sh"))
+///     .into_iter()
+///     .map(|provider| provider.name)
+///     .collect();
+/// assert_eq!(providers, [Name("sh"), Name("bash"), Name("dash")]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProviderIndex<'a, Package> {
+    packages: Vec<IndexedPackage<'a, Package>>,
+    providers: HashMap<&'a str, Vec<ProviderEntry>>,
+}
+
+/// Package with its queried information.
+#[derive(Debug, Clone)]
+struct IndexedPackage<'a, Package> {
+    package: Package,
+    name: Name<'a>,
+    version: Option<Version<'a>>,
+    provisions: Vec<Dependency<'a>>,
+}
+
+/// Reference to a package in a [`ProviderIndex`] under a provided name.
+#[derive(Debug, Clone, Copy)]
+struct ProviderEntry {
+    /// Index of the package.
+    package: usize,
+    /// Index of the provision, `None` means the package name itself.
+    provision: Option<usize>,
+}
+
+/// Candidate provider of a dependency, returned by [`ProviderIndex::providers`].
+#[derive(Debug, Clone, Copy)]
+pub struct Provider<'r, 'a, Package> {
+    /// The package.
+    pub package: &'r Package,
+    /// Name of the package.
+    pub name: Name<'a>,
+    /// Version of the package, if any.
+    pub version: Option<Version<'a>>,
+    /// Provision which satisfies the dependency, `None` means the package is an exact-name match.
+    pub provision: Option<Dependency<'a>>,
+}
+
+impl<'a, Package: Query<'a>> ProviderIndex<'a, Package> {
+    /// Build an index from packages.
+    ///
+    /// Packages without names are ignored.
+    pub fn new<Packages: IntoIterator<Item = Package>>(packages: Packages) -> Self {
+        let mut index = ProviderIndex {
+            packages: Vec::new(),
+            providers: HashMap::new(),
+        };
+        for package in packages {
+            index.insert(package);
+        }
+        index
+    }
+
+    /// Add a package to the index, return `false` if the package has no name.
+    pub fn insert(&mut self, package: Package) -> bool {
+        let Some(name) = package.name() else {
+            return false;
+        };
+        let version = package.version();
+        let provisions: Vec<_> = package
+            .provides()
+            .into_iter()
+            .flat_map(|provides| provides.into_iter())
+            .collect();
+        let package_index = self.packages.len();
+        self.add_provider(name.as_str(), package_index, None);
+        for (provision_index, provision) in provisions.iter().enumerate() {
+            self.add_provider(
+                provided_name(provision),
+                package_index,
+                Some(provision_index),
+            );
+        }
+        self.packages.push(IndexedPackage {
+            package,
+            name,
+            version,
+            provisions,
+        });
+        true
+    }
+
+    /// Register a package as a provider of a name.
+    fn add_provider(&mut self, name: &'a str, package: usize, provision: Option<usize>) {
+        self.providers
+            .entry(name)
+            .or_default()
+            .push(ProviderEntry { package, provision });
+    }
+}
+
+impl<'a, Package> ProviderIndex<'a, Package> {
+    /// Number of packages in the index.
+    pub fn len(&self) -> usize {
+        self.packages.len()
+    }
+
+    /// Whether the index has no packages.
+    pub fn is_empty(&self) -> bool {
+        self.packages.is_empty()
+    }
+
+    /// List all packages in the order they were inserted.
+    pub fn packages(&self) -> impl Iterator<Item = &Package> {
+        self.packages.iter().map(|indexed| &indexed.package)
+    }
+
+    /// Find the first package whose name is exactly `name`.
+    pub fn get(&self, name: &str) -> Option<&Package> {
+        self.providers
+            .get(name)?
+            .iter()
+            .find(|entry| entry.provision.is_none())
+            .map(|entry| &self.packages[entry.package].package)
+    }
+
+    /// List packages which can satisfy a dependency.
+    ///
+    /// Packages whose names are exactly the name of the dependency come first, followed by packages
+    /// which provide it, each group in the order the packages were inserted. Each package appears at
+    /// most once.
+    ///
+    /// A package satisfies the dependency by its name if its version satisfies the specification
+    /// (see [`Dependency::satisfied_by_package`]). A provision satisfies the dependency according to
+    /// [`Dependency::satisfied_by_provision`], which means an unversioned provision does not satisfy
+    /// a versioned dependency.
+    pub fn providers(&self, dependency: Dependency<'_>) -> Vec<Provider<'_, 'a, Package>> {
+        let Some(entries) = self.providers.get(provided_name(&dependency)) else {
+            return Vec::new();
+        };
+
+        let exact = entries.iter().filter_map(|entry| {
+            let package = &self.packages[entry.package];
+            if entry.provision.is_some()
+                || !dependency.satisfied_by_package(package.name, package.version)
+            {
+                return None;
+            }
+            Some((entry.package, None))
+        });
+        let provided = entries.iter().filter_map(|entry| {
+            let package = &self.packages[entry.package];
+            let provision = package.provisions[entry.provision?];
+            dependency
+                .satisfied_by_provision(&provision)
+                .then_some((entry.package, Some(provision)))
+        });
+
+        let mut providers: Vec<Provider<'_, 'a, Package>> = Vec::new();
+        let mut seen = HashSet::new();
+        for (package_index, provision) in exact.chain(provided) {
+            if !seen.insert(package_index) {
+                continue;
+            }
+            let package = &self.packages[package_index];
+            providers.push(Provider {
+                package: &package.package,
+                name: package.name,
+                version: package.version,
+                provision,
+            });
+        }
+        providers
+    }
+}
+
+/// Name under which a dependency or a provision is indexed.
+pub(super) fn provided_name<'a>(dependency: &Dependency<'a>) -> &'a str {
+    match dependency.soname() {
+        Some(soname) => soname.name().as_str(),
+        None => dependency.components().0.as_str(),
+    }
+}
@@ -0,0 +1,326 @@
+use super::{Provider, ProviderIndex, provider_index::provided_name};
+use crate::{
+    desc::Query,
+    value::{Dependency, Name, Version},
+};
+use derive_more::{Display, Error};
+use std::collections::VecDeque;
+
+/// Resolver of the packages which an installation would pull in.
+///
+/// The resolver follows `DEPENDS`, `PROVIDES`, and `CONFLICTS` of packages from a list of
+/// [repositories](ProviderIndex) in priority order. To take installed packages into account,
+/// put an index of the local database first.
+///
+/// A dependency is satisfied by a package with the exact name before a package which provides it,
+/// the repositories are searched in order for each of the two, and the first candidate wins.
+///
+/// ```
+/// # use arch_pkg_text::{parse::ParsedDesc, resolve::{ProviderIndex, Resolver}, value::Name};
+/// # use pretty_assertions::assert_eq;
+/// let core = [
+///     "%NAME%\nbash\n\n%VERSION%\n5.2.037-1\n\n%DEPENDS%\nreadline\nglibc\n",
+///     "%NAME%\nreadline\n\n%VERSION%\n8.2.013-1\n\n%DEPENDS%\nglibc\n",
+///     "%NAME%\nglibc\n\n%VERSION%\n2.40-1\n",
+/// ]
+/// .map(|text| ParsedDesc::parse(text).unwrap());
+/// let core = ProviderIndex::new(core);
+/// let resolution = Resolver::new([&core]).resolve(["bash"]);
+/// assert!(resolution.issues.is_empty());
+/// let names: Vec<_> = resolution.packages.iter().map(|package| package.name).collect();
+/// assert_eq!(names, [Name("glibc"), Name("readline"), Name("bash")]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Resolver<'r, 'a, Package> {
+    repositories: Vec<&'r ProviderIndex<'a, Package>>,
+}
+
+/// Package selected by the [`Resolver`].
+#[derive(Debug, Clone)]
+pub struct ResolvedPackage<'r, 'a, Package> {
+    /// The package.
+    pub package: &'r Package,
+    /// Name of the package.
+    pub name: Name<'a>,
+    /// Version of the package, if any.
+    pub version: Option<Version<'a>>,
+    /// Index of the repository of the package in the list given to [`Resolver::new`].
+    pub repository: usize,
+    /// Why the package was selected.
+    pub reason: ResolveReason<'a>,
+}
+
+/// Reason for selecting a [package](ResolvedPackage).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResolveReason<'a> {
+    /// The package satisfies one of the targets.
+    Target,
+    /// The package satisfies a dependency of another package.
+    Dependency {
+        /// Name of the dependent package.
+        package: Name<'a>,
+        /// The satisfied dependency.
+        dependency: Dependency<'a>,
+    },
+}
+
+/// Problem found by the [`Resolver`].
+#[derive(Debug, Display, Error, Clone, PartialEq, Eq)]
+pub enum ResolveIssue<'a> {
+    #[display("No package satisfies target {_0:?}")]
+    TargetNotFound(#[error(not(source))] String),
+    #[display("No package satisfies dependency {dependency} of {package}")]
+    UnresolvableDependency {
+        package: Name<'a>,
+        dependency: Dependency<'a>,
+    },
+    #[display("Dependency {dependency} of {package} is not satisfied by the selected {selected}")]
+    UnsatisfiedVersion {
+        package: Name<'a>,
+        dependency: Dependency<'a>,
+        /// The selected package whose name or provision has the name of the dependency.
+        selected: Name<'a>,
+    },
+    #[display("{package} conflicts with {other} ({conflict})")]
+    Conflict {
+        package: Name<'a>,
+        other: Name<'a>,
+        conflict: Dependency<'a>,
+    },
+}
+
+/// Result of [`Resolver::resolve`].
+#[derive(Debug, Clone)]
+pub struct Resolution<'r, 'a, Package> {
+    /// Selected packages, every package comes after its dependencies except when they depend on each other.
+    pub packages: Vec<ResolvedPackage<'r, 'a, Package>>,
+    /// Problems found during resolution, the resolution is only complete when this is empty.
+    pub issues: Vec<ResolveIssue<'a>>,
+}
+
+impl<'r, 'a, Package> Resolution<'r, 'a, Package> {
+    /// Return the packages if there are no issues, otherwise return the issues.
+    pub fn into_result(
+        self,
+    ) -> Result<Vec<ResolvedPackage<'r, 'a, Package>>, Vec<ResolveIssue<'a>>> {
+        match self.issues.is_empty() {
+            true => Ok(self.packages),
+            false => Err(self.issues),
+        }
+    }
+}
+
+/// Package which was selected during resolution.
+struct Selected<'r, 'a, Package> {
+    package: ResolvedPackage<'r, 'a, Package>,
+    provisions: Vec<Dependency<'a>>,
+    /// Indices of the selected packages which satisfy the dependencies of this package.
+    dependencies: Vec<usize>,
+}
+
+impl<'a, Package> Selected<'_, 'a, Package> {
+    /// Whether the selected package satisfies a dependency.
+    fn satisfies(&self, dependency: &Dependency<'_>) -> bool {
+        dependency.satisfied_by_package(self.package.name, self.package.version)
+            || self
+                .provisions
+                .iter()
+                .any(|provision| dependency.satisfied_by_provision(provision))
+    }
+
+    /// Whether the name or a provision of the selected package has the name of a dependency, regardless of versions.
+    fn has_name_of(&self, dependency: &Dependency<'_>) -> bool {
+        let name = provided_name(dependency);
+        self.package.name.as_str() == name
+            || self
+                .provisions
+                .iter()
+                .any(|provision| provided_name(provision) == name)
+    }
+}
+
+impl<'r, 'a, Package: Query<'a>> Resolver<'r, 'a, Package> {
+    /// Create a resolver from repositories in priority order.
+    pub fn new<Repositories>(repositories: Repositories) -> Self
+    where
+        Repositories: IntoIterator<Item = &'r ProviderIndex<'a, Package>>,
+    {
+        let repositories = repositories.into_iter().collect();
+        Resolver { repositories }
+    }
+
+    /// Resolve the packages which installing the targets would pull in.
+    ///
+    /// Each target is a package name or a name provided by a package (such as `sh`).
+    pub fn resolve<'t, Targets>(&self, targets: Targets) -> Resolution<'r, 'a, Package>
+    where
+        Targets: IntoIterator<Item = &'t str>,
+    {
+        let mut selected: Vec<Selected<'r, 'a, Package>> = Vec::new();
+        let mut issues = Vec::new();
+        let mut queue = VecDeque::new();
+
+        for target in targets {
+            let dependency = Dependency(target);
+            if selected.iter().any(|item| item.satisfies(&dependency)) {
+                continue;
+            }
+            match self.find_provider(dependency, &selected) {
+                Some((repository, provider)) => {
+                    queue.push_back(selected.len());
+                    selected.push(select(repository, provider, ResolveReason::Target));
+                }
+                None => issues.push(ResolveIssue::TargetNotFound(target.into())),
+            }
+        }
+
+        while let Some(index) = queue.pop_front() {
+            let package = selected[index].package.package;
+            let name = selected[index].package.name;
+            for dependency in package.dependencies().into_iter().flatten() {
+                if let Some(found) = selected.iter().position(|item| item.satisfies(&dependency)) {
+                    selected[index].dependencies.push(found);
+                    continue;
+                }
+                if let Some(other) = selected.iter().find(|item| item.has_name_of(&dependency)) {
+                    issues.push(ResolveIssue::UnsatisfiedVersion {
+                        package: name,
+                        dependency,
+                        selected: other.package.name,
+                    });
+                    continue;
+                }
+                let Some((repository, provider)) = self.find_provider(dependency, &selected) else {
+                    issues.push(ResolveIssue::UnresolvableDependency {
+                        package: name,
+                        dependency,
+                    });
+                    continue;
+                };
+                let reason = ResolveReason::Dependency {
+                    package: name,
+                    dependency,
+                };
+                let new_index = selected.len();
+                selected[index].dependencies.push(new_index);
+                queue.push_back(new_index);
+                selected.push(select(repository, provider, reason));
+            }
+        }
+
+        let mut conflicting_pairs: Vec<(Name<'a>, Name<'a>)> = Vec::new();
+        for item in &selected {
+            let conflicts = item.package.package.conflicts().into_iter().flatten();
+            for conflict in conflicts {
+                let others = selected
+                    .iter()
+                    .filter(|other| other.package.name != item.package.name)
+                    .filter(|other| other.satisfies(&conflict));
+                for other in others {
+                    // a pair of packages which conflict with each other is only reported once
+                    let pair = (item.package.name, other.package.name);
+                    if conflicting_pairs.contains(&pair)
+                        || conflicting_pairs.contains(&(pair.1, pair.0))
+                    {
+                        continue;
+                    }
+                    conflicting_pairs.push(pair);
+                    issues.push(ResolveIssue::Conflict {
+                        package: item.package.name,
+                        other: other.package.name,
+                        conflict,
+                    });
+                }
+            }
+        }
+
+        let packages = sort_topologically(selected);
+        Resolution { packages, issues }
+    }
+
+    /// Find a package which satisfies a dependency and whose name has not been selected.
+    ///
+    /// Exact name matches in every repository are preferred over provisions.
+    fn find_provider(
+        &self,
+        dependency: Dependency<'_>,
+        selected: &[Selected<'r, 'a, Package>],
+    ) -> Option<(usize, Provider<'r, 'a, Package>)> {
+        let is_selected = |name: Name| selected.iter().any(|item| item.package.name == name);
+        let candidates = || {
+            self.repositories
+                .iter()
+                .enumerate()
+                .flat_map(move |(repository, index)| {
+                    index
+                        .providers(dependency)
+                        .into_iter()
+                        .map(move |provider| (repository, provider))
+                })
+                .filter(|(_, provider)| !is_selected(provider.name))
+        };
+        candidates()
+            .find(|(_, provider)| provider.provision.is_none())
+            .or_else(|| candidates().next())
+    }
+}
+
+/// Record a provider as a selected package.
+fn select<'r, 'a, Package: Query<'a>>(
+    repository: usize,
+    provider: Provider<'r, 'a, Package>,
+    reason: ResolveReason<'a>,
+) -> Selected<'r, 'a, Package> {
+    let provisions = provider.package.provides().into_iter().flatten().collect();
+    let package = ResolvedPackage {
+        package: provider.package,
+        name: provider.name,
+        version: provider.version,
+        repository,
+        reason,
+    };
+    Selected {
+        package,
+        provisions,
+        dependencies: Vec::new(),
+    }
+}
+
+/// Order the selected packages so that dependencies come before their dependents.
+///
+/// Dependency cycles are broken at the package which was reached first, like pacman does.
+fn sort_topologically<'r, 'a, Package>(
+    selected: Vec<Selected<'r, 'a, Package>>,
+) -> Vec<ResolvedPackage<'r, 'a, Package>> {
+    let mut visited = vec![false; selected.len()];
+    let mut order = Vec::with_capacity(selected.len());
+    for root in 0..selected.len() {
+        if visited[root] {
+            continue;
+        }
+        visited[root] = true;
+        let mut stack = vec![(root, 0)];
+        while let Some((node, next)) = stack.last_mut() {
+            let node = *node;
+            if let Some(&child) = selected[node].dependencies.get(*next) {
+                *next += 1;
+                if !visited[child] {
+                    visited[child] = true;
+                    stack.push((child, 0));
+                }
+                continue;
+            }
+            order.push(node);
+            stack.pop();
+        }
+    }
+
+    let mut packages: Vec<_> = selected
+        .into_iter()
+        .map(|item| Some(item.package))
+        .collect();
+    order
+        .into_iter()
+        .filter_map(|index| packages[index].take())
+        .collect()
+}
@@ -1,6 +1,8 @@
 //! Fields and queriers of the text format of `.SRCINFO` files.
 
 pub mod misc;
+#[cfg(feature = "checksum")]
+pub mod verify;
 
 mod field;
 pub use field::*;
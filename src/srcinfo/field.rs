@@ -152,6 +152,8 @@ pub enum FieldName {
     Replaces,
 
     /* ANY SECTION: CHECKSUMS */
+    #[strum(serialize = "cksums")]
+    Crc32Checksums,
     #[strum(serialize = "md5sums")]
     Md5Checksums,
     #[strum(serialize = "sha1sums")]
@@ -168,5 +170,20 @@ pub enum FieldName {
     Blake2bChecksums,
 }
 
+impl FieldName {
+    /// Whether a `pkgname` section may override the field of the `pkgbase` section.
+    pub(crate) const fn is_inheritable(self) -> bool {
+        !matches!(
+            self,
+            FieldName::Base
+                | FieldName::Name
+                | FieldName::Epoch
+                | FieldName::Release
+                | FieldName::ValidPgpKeys
+                | FieldName::Version,
+        )
+    }
+}
+
 mod parse;
 pub use parse::*;
@@ -4,19 +4,22 @@ use pipe_trait::Pipe;
 
 /// Location of a given [`QueryItem`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Section<'a> {
     /// The item belongs to a section under `pkgbase`.
     Base,
     /// The item belongs to a section under `pkgname`.
-    Derivative(value::Name<'a>),
+    Derivative(#[cfg_attr(feature = "serde", serde(borrow))] value::Name<'a>),
 }
 
 /// Return type of methods in [`Query`] and [`QueryMut`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct QueryItem<'a, Value, Architecture> {
     /// Value of the item.
     pub value: Value,
     /// Location of the item.
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub section: Section<'a>,
     /// Architecture suffix of the corresponding field.
     pub architecture: Architecture,
@@ -115,6 +118,9 @@ impl<'a> QueryRawTextItem<'a> {
 /// Return type of [`Checksums::checksums`] and [`ChecksumsMut::checksums_mut`].
 pub type QueryChecksumItem<'a> = QueryItem<'a, ChecksumValue<'a>, Option<value::Architecture<'a>>>;
 
+/// Return type of [`ClearedFields::cleared_fields`] and [`ClearedFieldsMut::cleared_fields_mut`].
+pub type QueryClearedItem<'a> = QueryItem<'a, FieldName, Option<value::Architecture<'a>>>;
+
 macro_rules! def_traits {
     (
         base single {$(
@@ -259,6 +265,7 @@ def_traits! {
         replaces, replaces_mut = Replaces -> Dependency;
 
         /* CHECKSUMS */
+        crc32_checksums, crc32_checksums_mut = Crc32Checksums -> SkipOrCrc32;
         md5_checksums, md5_checksums_mut = Md5Checksums -> SkipOrHex128;
         sha1_checksums, sha1_checksums_mut = Sha1Checksums -> SkipOrHex160;
         sha224_checksums, sha224_checksums_mut = Sha224Checksums -> SkipOrHex224;
@@ -279,16 +286,38 @@ pub trait ChecksumsMut<'a> {
     fn checksums_mut(&mut self) -> impl Iterator<Item = QueryChecksumItem<'a>>;
 }
 
+/// Get fields which are explicitly cleared by `pkgname` sections from a querier of `.SRCINFO`.
+///
+/// `makepkg` writes an empty assignment (such as `depends =`) when a `package_*` function sets a field
+/// to an empty array, which means the package doesn't inherit the field from the `pkgbase` section.
+/// Each item carries the name of the cleared field (as value), its section, and its architecture suffix.
+pub trait ClearedFields<'a>: ClearedFieldsMut<'a> {
+    fn cleared_fields(&self) -> impl Iterator<Item = QueryClearedItem<'a>>;
+}
+
+/// Get fields which are explicitly cleared by `pkgname` sections from a querier of `.SRCINFO`, mutability required.
+pub trait ClearedFieldsMut<'a> {
+    fn cleared_fields_mut(&mut self) -> impl Iterator<Item = QueryClearedItem<'a>>;
+}
+
 pub(crate) mod utils;
 
 mod generic;
 
+mod architecture;
+pub use architecture::*;
+
 mod checksums;
 pub use checksums::*;
 
 mod forgetful;
 pub use forgetful::*;
 
+#[cfg(feature = "std")]
+mod source_checksums;
+#[cfg(feature = "std")]
+pub use source_checksums::*;
+
 #[cfg(feature = "std")]
 mod memo;
 #[cfg(feature = "std")]
@@ -0,0 +1,168 @@
+use super::{ChecksumValue, Checksums, Query, QueryItem};
+use crate::{srcinfo::field::FieldName, value};
+use derive_more::{Display, Error};
+
+/// View of a [querier](Query) for a target architecture (`CARCH`).
+///
+/// The view resolves fields with architecture suffixes the way `makepkg` does: the values of a field
+/// for the target architecture are the values without a suffix followed by the values with the suffix
+/// of the target architecture (e.g. `source` then `source_x86_64`). Values with suffixes of other
+/// architectures are dropped.
+///
+/// The view is best applied to a querier of a single package, such as
+/// [`ParsedSrcinfoPackage`](crate::parse::ParsedSrcinfoPackage).
+///
+/// ```
+/// # use arch_pkg_text::{srcinfo::{ArchitectureView, ForgetfulQuerier}, value::{Architecture, Dependency}};
+/// # use pretty_assertions::assert_eq;
+/// let querier = ForgetfulQuerier::new(concat!(
+///     "pkgbase = foo\n",
+///     "\tarch = x86_64\n",
+///     "\tarch = aarch64\n",
+///     "\tdepends_x86_64 = lib32-glibc\n",
+///     "\tdepends = glibc\n",
+///     "\tdepends_aarch64 = aarch64-compat\n",
+///     "pkgname = foo\n",
+/// ));
+///
+/// let view = ArchitectureView::new(querier, Architecture("x86_64")).unwrap();
+/// let dependencies: Vec<_> = view.dependencies().map(|item| item.value).collect();
+/// assert_eq!(dependencies, [Dependency("glibc"), Dependency("lib32-glibc")]);
+///
+/// let error = ArchitectureView::new(querier, Architecture("i686")).unwrap_err();
+/// assert_eq!(error.to_string(), "Architecture i686 is not supported");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ArchitectureView<'a, Querier> {
+    querier: Querier,
+    architecture: value::Architecture<'a>,
+}
+
+/// Error of [`ArchitectureView::new`] when the `arch` list supports neither the target architecture nor `any`.
+#[derive(Debug, Display, Error, Clone, Copy, PartialEq, Eq)]
+#[display("Architecture {architecture} is not supported")]
+pub struct UnsupportedArchitectureError<'a> {
+    /// The target architecture.
+    #[error(not(source))]
+    pub architecture: value::Architecture<'a>,
+}
+
+/// Return type of [`ArchitectureView::query_raw_text`].
+pub type ArchitectureViewRawTextItem<'a> = QueryItem<'a, &'a str, ()>;
+
+impl<'a, Querier: Query<'a>> ArchitectureView<'a, Querier> {
+    /// Create a view of a querier for a target architecture.
+    ///
+    /// Return an error if the `arch` values of the querier include neither `architecture` nor `any`.
+    pub fn new(
+        querier: Querier,
+        architecture: value::Architecture<'a>,
+    ) -> Result<Self, UnsupportedArchitectureError<'a>> {
+        let supported = querier
+            .architecture()
+            .any(|item| item.value.as_str() == "any" || item.value == architecture);
+        if !supported {
+            return Err(UnsupportedArchitectureError { architecture });
+        }
+        Ok(ArchitectureView {
+            querier,
+            architecture,
+        })
+    }
+
+    /// Query the raw values of a field for the target architecture.
+    ///
+    /// Values without architecture suffixes come first, followed by values with the suffix of the target architecture.
+    pub fn query_raw_text(
+        &self,
+        field_name: FieldName,
+    ) -> impl Iterator<Item = ArchitectureViewRawTextItem<'a>> {
+        let architecture = self.architecture;
+        let generic = self
+            .querier
+            .query_raw_text(field_name)
+            .filter(|item| item.architecture.is_none());
+        let specific = self
+            .querier
+            .query_raw_text(field_name)
+            .filter(move |item| item.architecture == Some(architecture));
+        generic
+            .chain(specific)
+            .map(|item| QueryItem::from_tuple3((item.value, item.section, ())))
+    }
+}
+
+impl<'a, Querier> ArchitectureView<'a, Querier> {
+    /// Get the target architecture.
+    pub fn architecture(&self) -> value::Architecture<'a> {
+        self.architecture
+    }
+
+    /// Get a reference to the underlying querier.
+    pub fn querier(&self) -> &'_ Querier {
+        &self.querier
+    }
+
+    /// Get the underlying querier.
+    pub fn into_querier(self) -> Querier {
+        self.querier
+    }
+}
+
+impl<'a, Querier: Checksums<'a>> ArchitectureView<'a, Querier> {
+    /// Get the checksums for the target architecture.
+    ///
+    /// Checksums without architecture suffixes come first, followed by checksums with the suffix of the target architecture.
+    pub fn checksums(&self) -> impl Iterator<Item = QueryItem<'a, ChecksumValue<'a>, ()>> {
+        let architecture = self.architecture;
+        let generic = self
+            .querier
+            .checksums()
+            .filter(|item| item.architecture.is_none());
+        let specific = self
+            .querier
+            .checksums()
+            .filter(move |item| item.architecture == Some(architecture));
+        generic
+            .chain(specific)
+            .map(|item| QueryItem::from_tuple3((item.value, item.section, ())))
+    }
+}
+
+macro_rules! def_methods {
+    ($(
+        $name:ident = $field:ident -> $value_type:ident;
+    )*) => {
+        impl<'a, Querier: Query<'a>> ArchitectureView<'a, Querier> {$(
+            #[doc = concat!("Get the values of `", stringify!($name), "` for the target architecture.")]
+            pub fn $name(&self) -> impl Iterator<Item = QueryItem<'a, value::$value_type<'a>, ()>> {
+                self.query_raw_text(FieldName::$field)
+                    .map(|item| item.map(value::$value_type::new))
+            }
+        )*}
+    };
+}
+
+def_methods! {
+    /* MISC */
+    source = Source -> Source;
+
+    /* DEPENDENCIES */
+    dependencies = Dependencies -> Dependency;
+    make_dependencies = MakeDependencies -> Dependency;
+    check_dependencies = CheckDependencies -> Dependency;
+    opt_dependencies = OptionalDependencies -> DependencyAndReason;
+    provides = Provides -> Dependency;
+    conflicts = Conflicts -> Dependency;
+    replaces = Replaces -> Dependency;
+
+    /* CHECKSUMS */
+    crc32_checksums = Crc32Checksums -> SkipOrCrc32;
+    md5_checksums = Md5Checksums -> SkipOrHex128;
+    sha1_checksums = Sha1Checksums -> SkipOrHex160;
+    sha224_checksums = Sha224Checksums -> SkipOrHex224;
+    sha256_checksums = Sha256Checksums -> SkipOrHex256;
+    sha384_checksums = Sha384Checksums -> SkipOrHex384;
+    sha512_checksums = Sha512Checksums -> SkipOrHex512;
+    blake2b_checksums = Blake2bChecksums -> SkipOrHex512;
+}
@@ -55,8 +55,15 @@ macro_rules! def_enum {
                 )*}
             }
 
+            /// Get the [`ChecksumType`] of the value.
+            pub const fn checksum_type(&self) -> ChecksumType {
+                match self {$(
+                    ChecksumValue::$checksum_variant(_) => ChecksumType::$checksum_variant,
+                )*}
+            }
+
             /// Attempt to create a [`ChecksumValue`] from a [`FieldName`] and a raw value.
-            pub(super) fn try_from_field_name(field_name: FieldName, raw_value: &'a str) -> Option<Self> {
+            pub(crate) fn try_from_field_name(field_name: FieldName, raw_value: &'a str) -> Option<Self> {
                 let checksum_type = ChecksumType::try_from_field_name(field_name)?;
                 Some(ChecksumValue::new(checksum_type, raw_value))
             }
@@ -94,6 +101,7 @@ macro_rules! def_enum {
 }
 
 def_enum! {
+    Crc32(SkipOrCrc32, 4) = Crc32Checksums,
     Md5(SkipOrHex128, 16) = Md5Checksums,
     Sha1(SkipOrHex160, 20) = Sha1Checksums,
     Sha224(SkipOrHex224, 28) = Sha224Checksums,
@@ -1,6 +1,6 @@
 use super::{
-    ChecksumValue, Checksums, ChecksumsMut, Query, QueryChecksumItem, QueryMut, QueryRawTextItem,
-    Section,
+    ChecksumValue, Checksums, ChecksumsMut, ClearedFields, ClearedFieldsMut, Query,
+    QueryChecksumItem, QueryClearedItem, QueryMut, QueryRawTextItem, Section,
     utils::{non_blank_trimmed_lines, parse_line},
 };
 use crate::{
@@ -28,12 +28,19 @@ impl<'a> ForgetfulQuerier<'a> {
     /// List all items of known fields.
     fn all_known_items(
         &self,
+    ) -> impl Iterator<Item = (Section<'a>, (ParsedField<&'a str>, &'a str))> {
+        self.all_known_items_with_empty_values()
+            .filter(|(_, (_, value))| !value.is_empty())
+    }
+
+    /// List all items of known fields, including those with empty values.
+    fn all_known_items_with_empty_values(
+        &self,
     ) -> impl Iterator<Item = (Section<'a>, (ParsedField<&'a str>, &'a str))> {
         self.0
             .pipe(non_blank_trimmed_lines)
             .map_while(parse_line)
             .filter_map(known_field)
-            .filter(|(_, value)| !value.is_empty())
             .scan_state_copy(Section::Base, scan_section)
     }
 }
@@ -78,6 +85,31 @@ impl<'a> ChecksumsMut<'a> for ForgetfulQuerier<'a> {
     }
 }
 
+impl<'a> ClearedFields<'a> for ForgetfulQuerier<'a> {
+    fn cleared_fields(&self) -> impl Iterator<Item = QueryClearedItem<'a>> {
+        self.all_known_items_with_empty_values()
+            .filter(|(section, (field, value))| {
+                value.is_empty()
+                    && field.name().is_inheritable()
+                    && matches!(section, Section::Derivative(_))
+            })
+            .map(|(section, (field, _))| {
+                (
+                    *field.name(),
+                    section,
+                    field.architecture_str().map(Architecture),
+                )
+            })
+            .map(QueryClearedItem::from_tuple3)
+    }
+}
+
+impl<'a> ClearedFieldsMut<'a> for ForgetfulQuerier<'a> {
+    fn cleared_fields_mut(&mut self) -> impl Iterator<Item = QueryClearedItem<'a>> {
+        self.cleared_fields()
+    }
+}
+
 /// Callback function to pass to `.filter_map` to filter out unknown fields.
 fn known_field<'a, Architecture, Acquaintance>(
     (field, acquaintance): (RawField<'a>, Acquaintance),
@@ -97,7 +129,7 @@ fn scan_section<'a>(
     (field, value): (ParsedField<&'a str>, &'a str),
 ) -> (Section<'a>, (ParsedField<&'a str>, &'a str)) {
     match field.name() {
-        FieldName::Name => (Section::Derivative(Name(value)), (field, value)),
+        FieldName::Name if !value.is_empty() => (Section::Derivative(Name(value)), (field, value)),
         _ => (section, (field, value)),
     }
 }
@@ -1,8 +1,8 @@
 mod cache;
 
 use super::{
-    ChecksumType, ChecksumValue, ChecksumsMut, QueryChecksumItem, QueryMut, QueryRawTextItem,
-    Section,
+    ChecksumType, ChecksumValue, ChecksumsMut, ClearedFieldsMut, QueryChecksumItem,
+    QueryClearedItem, QueryMut, QueryRawTextItem, Section,
     utils::{parse_line, trimmed_line_is_blank},
 };
 use crate::{
@@ -24,6 +24,7 @@ pub struct MemoQuerier<'a> {
     remaining_lines: Lines<'a>,
     current_section: Section<'a>,
     cache: Cache<'a>,
+    cleared: Vec<QueryClearedItem<'a>>,
 }
 
 impl<'a> MemoQuerier<'a> {
@@ -33,12 +34,14 @@ impl<'a> MemoQuerier<'a> {
             remaining_lines: srcinfo.lines(),
             current_section: Section::Base,
             cache: Cache::default(),
+            cleared: Vec::new(),
         }
     }
 
     /// Shrink the cache's capacity to fit its length.
     pub fn shrink_cache_to_fit(&mut self) {
         self.cache.shrink_to_fit();
+        self.cleared.shrink_to_fit();
     }
 
     /// Private function for testing the internal cache.
@@ -57,10 +60,16 @@ impl<'a> MemoQuerier<'a> {
         let Ok(field) = raw_field.to_parsed::<FieldName, &str>() else {
             return self.next_entry();
         };
+        let architecture = field.architecture_str().map(Architecture);
         if value.is_empty() {
+            if field.name().is_inheritable()
+                && matches!(self.current_section, Section::Derivative(_))
+            {
+                let item = (*field.name(), self.current_section, architecture);
+                self.cleared.push(QueryClearedItem::from_tuple3(item));
+            }
             return self.next_entry();
         }
-        let architecture = field.architecture_str().map(Architecture);
         if *field.name() == FieldName::Name && architecture.is_none() {
             self.current_section = value.pipe(Name).pipe(Section::Derivative);
         }
@@ -165,6 +174,13 @@ impl<'a> ChecksumsMut<'a> for MemoQuerier<'a> {
     }
 }
 
+impl<'a> ClearedFieldsMut<'a> for MemoQuerier<'a> {
+    fn cleared_fields_mut(&mut self) -> impl Iterator<Item = QueryClearedItem<'a>> {
+        while self.next_entry().is_some() {}
+        self.cleared.iter().copied()
+    }
+}
+
 impl ReuseAdvice for MemoQuerier<'_> {
     /// [`MemoQuerier`] costs O(1) time to construct. Performing a lookup on it
     /// costs O(n) the first time and O(1) after that.
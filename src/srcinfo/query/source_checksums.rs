@@ -0,0 +1,215 @@
+use super::{ChecksumType, ChecksumValue, Checksums, Query, Section};
+use crate::value::{Architecture, Source};
+use core::fmt::{self, Display, Formatter};
+use derive_more::Error;
+
+/// Sources of a [querier](Query) paired with their [checksums](Checksums).
+///
+/// Like `makepkg`, a source is paired with the checksums at the same position in the checksum arrays
+/// of the same section and the same architecture suffix (e.g. `source_aarch64` with `sha256sums_aarch64`).
+///
+/// A checksum type is in use in a section when any of its arrays in the section has an entry, and every
+/// array of a checksum type in use must have as many entries as the corresponding `source` array.
+/// Arrays which don't are reported as [mismatches](ChecksumCountMismatch) and left out of the pairs.
+///
+/// ```
+/// # use arch_pkg_text::{srcinfo::{ChecksumType, ForgetfulQuerier, SourceChecksumPairs}, value::Source};
+/// # use pretty_assertions::assert_eq;
+/// let querier = ForgetfulQuerier::new(concat!(
+///     "pkgbase = foo\n",
+///     "\tsource = foo.tar.gz\n",
+///     "\tsha256sums = SKIP\n",
+///     "\tsource_aarch64 = foo-aarch64.patch\n",
+///     "\tsource_aarch64 = bar-aarch64.patch\n",
+///     "\tsha256sums_aarch64 = SKIP\n",
+///     "pkgname = foo\n",
+/// ));
+/// let pairs = SourceChecksumPairs::new(&querier);
+///
+/// let sources: Vec<_> = pairs.entries.iter().map(|entry| entry.source).collect();
+/// assert_eq!(
+///     sources,
+///     [Source("foo.tar.gz"), Source("foo-aarch64.patch"), Source("bar-aarch64.patch")],
+/// );
+/// assert_eq!(pairs.entries[0].checksums.len(), 1);
+/// assert_eq!(pairs.entries[1].checksums.len(), 0);
+///
+/// let [mismatch] = pairs.mismatches.as_slice() else { panic!() };
+/// assert_eq!(mismatch.checksum_type, ChecksumType::Sha256);
+/// assert_eq!((mismatch.sources, mismatch.checksums), (2, 1));
+/// assert_eq!(
+///     mismatch.to_string(),
+///     "sha256sums_aarch64 of pkgbase has 1 entry but source_aarch64 has 2",
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SourceChecksumPairs<'a> {
+    /// Sources with their checksums, grouped by section and architecture in the order of their appearances.
+    pub entries: Vec<SourceWithChecksums<'a>>,
+    /// Checksum arrays whose numbers of entries differ from their `source` arrays.
+    pub mismatches: Vec<ChecksumCountMismatch<'a>>,
+}
+
+/// Source with its checksums, an item of [`SourceChecksumPairs::entries`].
+#[derive(Debug, Clone)]
+pub struct SourceWithChecksums<'a> {
+    /// The source.
+    pub source: Source<'a>,
+    /// Section of the source.
+    pub section: Section<'a>,
+    /// Architecture suffix of the source.
+    pub architecture: Option<Architecture<'a>>,
+    /// Checksums of the source, one for each checksum type in use, in the order of [`ChecksumType::TYPES`].
+    ///
+    /// A checksum type whose array of the same section and architecture suffix is a [mismatch](ChecksumCountMismatch)
+    /// is left out for every source of the array.
+    pub checksums: Vec<ChecksumValue<'a>>,
+}
+
+/// Checksum array whose number of entries differs from its `source` array, an item of [`SourceChecksumPairs::mismatches`].
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub struct ChecksumCountMismatch<'a> {
+    /// Section of the arrays.
+    #[error(not(source))]
+    pub section: Section<'a>,
+    /// Architecture suffix of the arrays.
+    pub architecture: Option<Architecture<'a>>,
+    /// Type of the checksum array.
+    pub checksum_type: ChecksumType,
+    /// Number of entries of the `source` array.
+    pub sources: usize,
+    /// Number of entries of the checksum array.
+    pub checksums: usize,
+}
+
+impl Display for ChecksumCountMismatch<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let field_name = self.checksum_type.into_field_name();
+        write!(f, "{field_name}")?;
+        if let Some(architecture) = self.architecture {
+            write!(f, "_{architecture}")?;
+        }
+        match self.section {
+            Section::Base => write!(f, " of pkgbase")?,
+            Section::Derivative(name) => write!(f, " of pkgname {name}")?,
+        }
+        let entries = if self.checksums == 1 {
+            "entry"
+        } else {
+            "entries"
+        };
+        write!(f, " has {} {entries} but source", self.checksums)?;
+        if let Some(architecture) = self.architecture {
+            write!(f, "_{architecture}")?;
+        }
+        write!(f, " has {}", self.sources)
+    }
+}
+
+/// Sources and checksums of the same section and architecture suffix.
+struct Group<'a> {
+    section: Section<'a>,
+    architecture: Option<Architecture<'a>>,
+    sources: Vec<Source<'a>>,
+    checksums: Vec<Vec<ChecksumValue<'a>>>,
+}
+
+impl<'a> SourceChecksumPairs<'a> {
+    /// Pair the sources of a querier with its checksums.
+    pub fn new<Querier>(querier: &Querier) -> Self
+    where
+        Querier: Query<'a> + Checksums<'a>,
+    {
+        let mut groups: Vec<Group<'a>> = Vec::new();
+        for item in querier.source() {
+            group_of(&mut groups, item.section, item.architecture)
+                .sources
+                .push(item.value);
+        }
+        for item in querier.checksums() {
+            let type_index = checksum_type_index(item.value.checksum_type());
+            group_of(&mut groups, item.section, item.architecture).checksums[type_index]
+                .push(item.value);
+        }
+
+        let mut pairs = SourceChecksumPairs::default();
+        for group in &groups {
+            let in_use = |type_index: usize| {
+                groups
+                    .iter()
+                    .filter(|other| other.section == group.section)
+                    .any(|other| !other.checksums[type_index].is_empty())
+            };
+            let mut paired_types = Vec::new();
+            for (type_index, checksum_type) in ChecksumType::TYPES.iter().enumerate() {
+                if !in_use(type_index) {
+                    continue;
+                }
+                let checksums = group.checksums[type_index].len();
+                if checksums == group.sources.len() {
+                    paired_types.push(type_index);
+                    continue;
+                }
+                pairs.mismatches.push(ChecksumCountMismatch {
+                    section: group.section,
+                    architecture: group.architecture,
+                    checksum_type: *checksum_type,
+                    sources: group.sources.len(),
+                    checksums,
+                });
+            }
+            for (position, source) in group.sources.iter().enumerate() {
+                let checksums = paired_types
+                    .iter()
+                    .map(|type_index| group.checksums[*type_index][position])
+                    .collect();
+                pairs.entries.push(SourceWithChecksums {
+                    source: *source,
+                    section: group.section,
+                    architecture: group.architecture,
+                    checksums,
+                });
+            }
+        }
+        pairs
+    }
+
+    /// Return the entries if there are no mismatches, otherwise return the mismatches.
+    pub fn into_result(
+        self,
+    ) -> Result<Vec<SourceWithChecksums<'a>>, Vec<ChecksumCountMismatch<'a>>> {
+        match self.mismatches.is_empty() {
+            true => Ok(self.entries),
+            false => Err(self.mismatches),
+        }
+    }
+}
+
+/// Get the group of a section and an architecture suffix, create one if it doesn't exist yet.
+fn group_of<'a, 'groups>(
+    groups: &'groups mut Vec<Group<'a>>,
+    section: Section<'a>,
+    architecture: Option<Architecture<'a>>,
+) -> &'groups mut Group<'a> {
+    let index = groups
+        .iter()
+        .position(|group| group.section == section && group.architecture == architecture)
+        .unwrap_or_else(|| {
+            groups.push(Group {
+                section,
+                architecture,
+                sources: Vec::new(),
+                checksums: vec![Vec::new(); ChecksumType::TYPES.len()],
+            });
+            groups.len() - 1
+        });
+    &mut groups[index]
+}
+
+/// Get the position of a checksum type in [`ChecksumType::TYPES`].
+fn checksum_type_index(checksum_type: ChecksumType) -> usize {
+    ChecksumType::TYPES
+        .iter()
+        .position(|item| *item == checksum_type)
+        .expect("every checksum type is listed in ChecksumType::TYPES")
+}
@@ -0,0 +1,279 @@
+//! Compute and verify checksums of source files.
+
+use super::{ChecksumArray, ChecksumType, ChecksumValue};
+use derive_more::{Display, Error};
+use pipe_trait::Pipe;
+use sha2::Digest;
+use std::{
+    fs::File,
+    io::{self, Read, Write},
+    path::Path,
+};
+
+/// Incremental hasher of a [`ChecksumType`].
+///
+/// It implements [`Write`], so a reader can be [copied](io::copy) into it.
+///
+/// ```
+/// # use arch_pkg_text::srcinfo::{ChecksumArray, ChecksumType, verify::Hasher};
+/// # use pretty_assertions::assert_eq;
+/// let mut hasher = Hasher::new(ChecksumType::Md5);
+/// hasher.update(b"hello ");
+/// hasher.update(b"world");
+/// let ChecksumArray::Md5(array) = hasher.finalize() else { panic!() };
+/// assert_eq!(array[..4], [0x5e, 0xb6, 0x3b, 0xbb]);
+/// ```
+#[derive(Debug, Clone)]
+pub enum Hasher {
+    Crc32(Crc32),
+    Md5(md5::Md5),
+    Sha1(sha1::Sha1),
+    Sha224(sha2::Sha224),
+    Sha256(sha2::Sha256),
+    Sha384(sha2::Sha384),
+    Sha512(sha2::Sha512),
+    Blake2b(blake2::Blake2b512),
+}
+
+impl Hasher {
+    /// Create a hasher of a checksum type.
+    pub fn new(checksum_type: ChecksumType) -> Self {
+        match checksum_type {
+            ChecksumType::Crc32 => Hasher::Crc32(Crc32::default()),
+            ChecksumType::Md5 => Hasher::Md5(Digest::new()),
+            ChecksumType::Sha1 => Hasher::Sha1(Digest::new()),
+            ChecksumType::Sha224 => Hasher::Sha224(Digest::new()),
+            ChecksumType::Sha256 => Hasher::Sha256(Digest::new()),
+            ChecksumType::Sha384 => Hasher::Sha384(Digest::new()),
+            ChecksumType::Sha512 => Hasher::Sha512(Digest::new()),
+            ChecksumType::Blake2b => Hasher::Blake2b(Digest::new()),
+        }
+    }
+
+    /// Get the checksum type of the hasher.
+    pub const fn checksum_type(&self) -> ChecksumType {
+        match self {
+            Hasher::Crc32(_) => ChecksumType::Crc32,
+            Hasher::Md5(_) => ChecksumType::Md5,
+            Hasher::Sha1(_) => ChecksumType::Sha1,
+            Hasher::Sha224(_) => ChecksumType::Sha224,
+            Hasher::Sha256(_) => ChecksumType::Sha256,
+            Hasher::Sha384(_) => ChecksumType::Sha384,
+            Hasher::Sha512(_) => ChecksumType::Sha512,
+            Hasher::Blake2b(_) => ChecksumType::Blake2b,
+        }
+    }
+
+    /// Feed data into the hasher.
+    pub fn update(&mut self, data: &[u8]) {
+        match self {
+            Hasher::Crc32(hasher) => hasher.update(data),
+            Hasher::Md5(hasher) => hasher.update(data),
+            Hasher::Sha1(hasher) => hasher.update(data),
+            Hasher::Sha224(hasher) => hasher.update(data),
+            Hasher::Sha256(hasher) => hasher.update(data),
+            Hasher::Sha384(hasher) => hasher.update(data),
+            Hasher::Sha512(hasher) => hasher.update(data),
+            Hasher::Blake2b(hasher) => hasher.update(data),
+        }
+    }
+
+    /// Consume the hasher and return the digest.
+    pub fn finalize(self) -> ChecksumArray {
+        match self {
+            Hasher::Crc32(hasher) => ChecksumArray::Crc32(hasher.finalize().to_be_bytes()),
+            Hasher::Md5(hasher) => ChecksumArray::Md5(hasher.finalize().into()),
+            Hasher::Sha1(hasher) => ChecksumArray::Sha1(hasher.finalize().into()),
+            Hasher::Sha224(hasher) => ChecksumArray::Sha224(hasher.finalize().into()),
+            Hasher::Sha256(hasher) => ChecksumArray::Sha256(hasher.finalize().into()),
+            Hasher::Sha384(hasher) => ChecksumArray::Sha384(hasher.finalize().into()),
+            Hasher::Sha512(hasher) => ChecksumArray::Sha512(hasher.finalize().into()),
+            Hasher::Blake2b(hasher) => ChecksumArray::Blake2b(hasher.finalize().into()),
+        }
+    }
+}
+
+/// Hasher of the CRC computed by `cksum`, the checksum of `cksums`.
+///
+/// ```
+/// # use arch_pkg_text::srcinfo::verify::Crc32;
+/// let mut hasher = Crc32::default();
+/// hasher.update(b"hello world\n");
+/// assert_eq!(hasher.finalize(), 3733384285);
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Crc32 {
+    crc: u32,
+    length: u64,
+}
+
+/// Generator polynomial of the CRC of `cksum`.
+const CRC32_POLYNOMIAL: u32 = 0x04C1_1DB7;
+
+/// Lookup table of the CRC of `cksum`, indexed by the most significant byte.
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut index = 0;
+    while index < table.len() {
+        let mut crc = (index as u32) << 24;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = match crc & 0x8000_0000 {
+                0 => crc << 1,
+                _ => (crc << 1) ^ CRC32_POLYNOMIAL,
+            };
+            bit += 1;
+        }
+        table[index] = crc;
+        index += 1;
+    }
+    table
+};
+
+impl Crc32 {
+    /// Feed a byte into the CRC.
+    fn update_byte(&mut self, byte: u8) {
+        let index = ((self.crc >> 24) as u8 ^ byte) as usize;
+        self.crc = (self.crc << 8) ^ CRC32_TABLE[index];
+    }
+
+    /// Feed data into the hasher.
+    pub fn update(&mut self, data: &[u8]) {
+        for byte in data {
+            self.update_byte(*byte);
+        }
+        self.length += data.len() as u64;
+    }
+
+    /// Consume the hasher and return the CRC.
+    ///
+    /// Like `cksum`, the length of the data is fed into the CRC before it is complemented.
+    pub fn finalize(mut self) -> u32 {
+        let mut length = self.length;
+        while length != 0 {
+            self.update_byte(length as u8);
+            length >>= 8;
+        }
+        !self.crc
+    }
+}
+
+impl Write for Hasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl ChecksumType {
+    /// Compute the digest of a byte slice.
+    pub fn digest(self, data: &[u8]) -> ChecksumArray {
+        let mut hasher = Hasher::new(self);
+        hasher.update(data);
+        hasher.finalize()
+    }
+
+    /// Compute the digest of everything a reader yields.
+    pub fn digest_reader(self, mut reader: impl Read) -> io::Result<ChecksumArray> {
+        let mut hasher = Hasher::new(self);
+        io::copy(&mut reader, &mut hasher)?;
+        Ok(hasher.finalize())
+    }
+
+    /// Compute the digest of a file.
+    pub fn digest_file(self, path: impl AsRef<Path>) -> io::Result<ChecksumArray> {
+        path.pipe(File::open)?.pipe(|file| self.digest_reader(file))
+    }
+}
+
+/// Success value of [`ChecksumValue::verify`], [`ChecksumValue::verify_reader`], and [`ChecksumValue::verify_file`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verification {
+    /// The checksum was `SKIP`, the data was not read.
+    Skipped,
+    /// The digest of the data matched the checksum.
+    Passed,
+    /// The digest of the data differed from the checksum.
+    Failed {
+        /// The checksum.
+        expected: ChecksumArray,
+        /// The digest of the data.
+        actual: ChecksumArray,
+    },
+}
+
+impl Verification {
+    /// Whether the data was accepted, i.e. the checksum was either `SKIP` or matched.
+    pub const fn is_accepted(&self) -> bool {
+        !matches!(self, Verification::Failed { .. })
+    }
+}
+
+/// Error type of [`ChecksumValue::verify`], [`ChecksumValue::verify_reader`], and [`ChecksumValue::verify_file`].
+#[derive(Debug, Display, Error)]
+pub enum VerifyChecksumError {
+    #[display("Value of {} is not a valid checksum", _0.into_field_name())]
+    InvalidChecksum(#[error(not(source))] ChecksumType),
+    #[display("Failed to read the data: {_0}")]
+    Io(io::Error),
+}
+
+impl From<io::Error> for VerifyChecksumError {
+    fn from(error: io::Error) -> Self {
+        VerifyChecksumError::Io(error)
+    }
+}
+
+impl ChecksumValue<'_> {
+    /// Compare the checksum with the digest of a byte slice.
+    ///
+    /// ```
+    /// # use arch_pkg_text::srcinfo::{ChecksumType, ChecksumValue, verify::Verification};
+    /// let checksum = ChecksumValue::new(ChecksumType::Sha1, "2aae6c35c94fcfb415dbe95f408b9ce91ee846ed");
+    /// assert_eq!(checksum.verify(b"hello world").unwrap(), Verification::Passed);
+    /// assert!(!checksum.verify(b"goodbye world").unwrap().is_accepted());
+    ///
+    /// let checksum = ChecksumValue::new(ChecksumType::Sha1, "SKIP");
+    /// assert_eq!(checksum.verify(b"goodbye world").unwrap(), Verification::Skipped);
+    /// ```
+    pub fn verify(self, data: &[u8]) -> Result<Verification, VerifyChecksumError> {
+        self.verify_with(|checksum_type| Ok(checksum_type.digest(data)))
+    }
+
+    /// Compare the checksum with the digest of everything a reader yields.
+    ///
+    /// The reader is left untouched if the checksum is `SKIP`.
+    pub fn verify_reader(self, reader: impl Read) -> Result<Verification, VerifyChecksumError> {
+        self.verify_with(|checksum_type| checksum_type.digest_reader(reader))
+    }
+
+    /// Compare the checksum with the digest of a file.
+    ///
+    /// The file is not opened if the checksum is `SKIP`.
+    pub fn verify_file(self, path: impl AsRef<Path>) -> Result<Verification, VerifyChecksumError> {
+        self.verify_with(|checksum_type| checksum_type.digest_file(path))
+    }
+
+    /// Compare the checksum with the digest computed by `digest` unless the checksum is `SKIP`.
+    fn verify_with(
+        self,
+        digest: impl FnOnce(ChecksumType) -> io::Result<ChecksumArray>,
+    ) -> Result<Verification, VerifyChecksumError> {
+        let checksum_type = self.checksum_type();
+        let expected = self
+            .u8_array()
+            .ok_or(VerifyChecksumError::InvalidChecksum(checksum_type))?;
+        if expected == ChecksumArray::Skip {
+            return Ok(Verification::Skipped);
+        }
+        let actual = digest(checksum_type)?;
+        Ok(match actual == expected {
+            true => Verification::Passed,
+            false => Verification::Failed { expected, actual },
+        })
+    }
+}
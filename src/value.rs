@@ -90,6 +90,8 @@ macro_rules! def_str_wrappers {
     )*) => {$(
         $(#[$attrs])*
         #[derive(Debug, Display, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, AsRef, Deref)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(transparent))]
         pub struct $name<'a>(pub &'a str);
         impl_str!($name);
     )*};
@@ -102,6 +104,8 @@ macro_rules! def_structured_wrappers {
     )*) => {$(
         $(#[$attrs])*
         #[derive(Debug, Display, Clone, Copy, AsRef, Deref)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(transparent))]
         pub struct $name<'a>(pub &'a str);
         impl_str!($name);
     )*};
@@ -116,6 +120,8 @@ macro_rules! def_hex_wrappers {
     )*) => {$(
         $(#[$attrs])*
         #[derive(Debug, Display, Clone, Copy, AsRef, Deref)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(transparent))]
         pub struct $name<'a>(pub &'a str);
         impl_str!($name);
         impl_hex!($name, $size);
@@ -131,6 +137,8 @@ macro_rules! def_srcinfo_checksum_wrappers {
     )*) => {$(
         $(#[$attrs])*
         #[derive(Debug, Display, Clone, Copy, AsRef, Deref)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(transparent))]
         pub struct $name<'a>(pub &'a str);
         impl_str!($name);
         impl_srcinfo_checksum!($name, $size);
@@ -144,6 +152,8 @@ macro_rules! def_b64_wrappers {
     )*) => {$(
         $(#[$attrs])*
         #[derive(Debug, Display, Clone, Copy, AsRef, Deref)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(transparent))]
         pub struct $name<'a>(pub &'a str);
         impl_str!($name);
     )*};
@@ -156,25 +166,15 @@ macro_rules! def_num_wrappers {
     )*) => {$(
         $(#[$attrs])*
         #[derive(Debug, Display, Clone, Copy, AsRef, Deref)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(transparent))]
         pub struct $name<'a>(&'a str);
         impl_num!($name, $num);
     )*};
 }
 
-macro_rules! def_list_wrappers {
-    ($(
-        $(#[$container_attrs:meta])*
-        $container_name:ident {
-            $(#[$iter_attrs:meta])*
-            Iter = $iter_name:ident;
-            $(#[$item_attrs:meta])*
-            Item = $item_name:ident;
-        }
-    )*) => {$(
-        $(#[$container_attrs])*
-        #[derive(Debug, Clone, Copy)]
-        pub struct $container_name<'a>(&'a str);
-
+macro_rules! impl_list {
+    ($container_name:ident, $iter_name:ident, $item_name:ident) => {
         impl<'a> $container_name<'a> {
             /// Create the wrapper.
             pub fn new(text: &'a str) -> Self {
@@ -195,10 +195,6 @@ macro_rules! def_list_wrappers {
             }
         }
 
-        $(#[$iter_attrs])*
-        #[derive(Debug, Clone)]
-        pub struct $iter_name<'a>(Split<'a, char>);
-
         impl<'a> Iterator for $iter_name<'a> {
             type Item = $item_name<'a>;
             fn next(&mut self) -> Option<Self::Item> {
@@ -217,10 +213,63 @@ macro_rules! def_list_wrappers {
 
         impl<'a> FusedIterator for $iter_name<'a> {}
 
+        /// Serialize the list as an array of its items.
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $container_name<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_seq(self.iter())
+            }
+        }
+    };
+}
+
+macro_rules! def_list_wrappers {
+    ($(
+        $(#[$container_attrs:meta])*
+        $container_name:ident {
+            $(#[$iter_attrs:meta])*
+            Iter = $iter_name:ident;
+            $(#[$item_attrs:meta])*
+            Item = $item_name:ident;
+        }
+    )*) => {$(
+        $(#[$container_attrs])*
+        #[derive(Debug, Clone, Copy)]
+        pub struct $container_name<'a>(&'a str);
+
+        $(#[$iter_attrs])*
+        #[derive(Debug, Clone)]
+        pub struct $iter_name<'a>(Split<'a, char>);
+
         $(#[$item_attrs])*
         #[derive(Debug, Display, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, AsRef, Deref)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(transparent))]
         pub struct $item_name<'a>(pub &'a str);
         impl_str!($item_name);
+
+        impl_list!($container_name, $iter_name, $item_name);
+    )*};
+}
+
+macro_rules! def_list_wrappers_of_existing_items {
+    ($(
+        $(#[$container_attrs:meta])*
+        $container_name:ident {
+            $(#[$iter_attrs:meta])*
+            Iter = $iter_name:ident;
+            Item = $item_name:ident;
+        }
+    )*) => {$(
+        $(#[$container_attrs])*
+        #[derive(Debug, Clone, Copy)]
+        pub struct $container_name<'a>(&'a str);
+
+        $(#[$iter_attrs])*
+        #[derive(Debug, Clone)]
+        pub struct $iter_name<'a>(Split<'a, char>);
+
+        impl_list!($container_name, $iter_name, $item_name);
     )*};
 }
 
@@ -239,7 +288,7 @@ def_str_wrappers! {
     Packager;
     /// Type of value of `changelog`.
     ChangeLog;
-    /// Type of value of `backup`.
+    /// Type of value of `backup` and of [iterator item](Iterator::Item) of [`FilePathList`].
     FilePath;
     /// Type of value of `source`.
     Source;
@@ -254,6 +303,12 @@ def_structured_wrappers! {
     BuildOption;
     /// Type of value of `validpgpkeys`.
     PgpKey;
+    /// Type of value of `REASON`.
+    InstallReason;
+    /// Type of value of `pkgrel`.
+    Release;
+    /// Type of value of `cksums`.
+    SkipOrCrc32;
 }
 
 def_hex_wrappers! {
@@ -300,14 +355,12 @@ def_b64_wrappers! {
 }
 
 def_num_wrappers! {
-    /// Type of value of `CSIZE` and `ISIZE`.
+    /// Type of value of `CSIZE`, `ISIZE`, and `SIZE`.
     Size = u64;
-    /// Type of value of `BUILDDATE`.
+    /// Type of value of `BUILDDATE` and `INSTALLDATE`.
     Timestamp = u64;
     /// Type of value of `epoch`.
     Epoch = u64;
-    /// Type of value of `pkgrel`.
-    Release = u64; // TODO: change this to allow `a.b` syntax
 }
 
 def_list_wrappers! {
@@ -350,6 +403,39 @@ def_list_wrappers! {
         /// Type of [iterator item](Iterator::Item) of [`DependencyAndReasonList`].
         Item = DependencyAndReason;
     }
+
+    /// Type of value of `VALIDATION`.
+    ValidationList {
+        /// [Iterator] type of [`ValidationList`].
+        Iter = ValidationIterator;
+        /// Type of [iterator item](Iterator::Item) of [`ValidationList`].
+        Item = Validation;
+    }
+
+    /// Type of value of `XDATA`.
+    XDataList {
+        /// [Iterator] type of [`XDataList`].
+        Iter = XDataIterator;
+        /// Type of [iterator item](Iterator::Item) of [`XDataList`].
+        Item = XData;
+    }
+
+    /// Type of value of `BACKUP`.
+    BackupList {
+        /// [Iterator] type of [`BackupList`].
+        Iter = BackupIterator;
+        /// Type of [iterator item](Iterator::Item) of [`BackupList`].
+        Item = BackupEntry;
+    }
+}
+
+def_list_wrappers_of_existing_items! {
+    /// Type of value of `FILES`.
+    FilePathList {
+        /// [Iterator] type of [`FilePathList`].
+        Iter = FilePathIterator;
+        Item = FilePath;
+    }
 }
 
 def_str_wrappers! {
@@ -359,25 +445,53 @@ def_str_wrappers! {
     DependencyReason;
     /// Specification of a [dependency](DependencyName).
     DependencySpecification;
+    /// Key of an [extended data entry](XData).
+    XDataKey;
+    /// Value of an [extended data entry](XData).
+    XDataValue;
 }
 
+mod backup_entry;
+mod constraint_version;
+mod crc32;
 mod dependency;
 mod dependency_and_reason;
 mod dependency_name;
 mod dependency_specification;
 mod dependency_specification_operator;
 mod hex128;
+mod install_reason;
 mod parse_array;
+mod parse_base64;
 mod parse_hex;
+mod pgp_signature;
+mod release;
 mod skip_or_array;
+mod soname;
+mod source;
 mod upstream_version;
+mod validation;
+mod vercmp;
 mod version;
+mod xdata;
 
+pub use constraint_version::ParsedConstraintVersion;
 pub use dependency_specification_operator::DependencySpecificationOperator;
+pub use install_reason::ParsedInstallReason;
 pub use parse_array::ParseArray;
+pub use parse_base64::DecodeBase64Error;
+#[cfg(feature = "std")]
+pub use pgp_signature::ParsePgpSignatureError;
+pub use pgp_signature::{
+    ParsePgpSignaturePacketError, ParsedPgpSignature, PgpFingerprint, PgpHashAlgorithm,
+};
+pub use release::{ParseReleaseError, ParsedRelease};
 pub use skip_or_array::SkipOrArray;
+pub use soname::{ParsedSoname, SonameBits};
+pub use source::{ParsedSource, SourceFileName, SourceFragment, SourceVcs};
 pub use upstream_version::{
     UpstreamVersionComponent, UpstreamVersionComponentIter, ValidUpstreamVersion,
     ValidateUpstreamVersionError,
 };
+pub use validation::ParsedValidation;
 pub use version::{ParseVersionError, ParsedVersion, SplitVersionError};
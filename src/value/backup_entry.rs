@@ -0,0 +1,28 @@
+use super::{BackupEntry, FilePath, Hex128};
+
+impl<'a> BackupEntry<'a> {
+    /// Extract [`FilePath`] and the [MD5 checksum](Hex128) of the file.
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::{BackupEntry, FilePath};
+    /// # use pretty_assertions::assert_eq;
+    /// let entry = BackupEntry("etc/pacman.conf\t2a2ee1f6ec3ee3b7bc1c3d42e4ab3c7c");
+    /// let (path, checksum) = entry.components();
+    /// assert_eq!(path, FilePath("etc/pacman.conf"));
+    /// assert_eq!(checksum.unwrap().as_str(), "2a2ee1f6ec3ee3b7bc1c3d42e4ab3c7c");
+    ///
+    /// let entry = BackupEntry("etc/pacman.conf");
+    /// let (path, checksum) = entry.components();
+    /// assert_eq!(path, FilePath("etc/pacman.conf"));
+    /// assert!(checksum.is_none());
+    /// ```
+    pub fn components(&self) -> (FilePath<'a>, Option<Hex128<'a>>) {
+        match self.split_once('\t') {
+            Some((path, checksum)) => (
+                FilePath(path),
+                (!checksum.is_empty()).then_some(Hex128(checksum)),
+            ),
+            None => (FilePath(self.0), None),
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use super::{
+    Epoch, ParseVersionError, ParsedRelease, ParsedVersion, Release, UpstreamVersion,
+    ValidUpstreamVersion, Version,
+};
+use core::cmp::Ordering;
+
+/// Result of [`Version::parse_constraint`].
+///
+/// Unlike [`ParsedVersion`], both the epoch and the release are optional, which is the usual
+/// form of versions in [dependency specifications](super::DependencySpecification)
+/// (e.g. `glibc>=2.39` or `python=3.12`).
+///
+/// A constraint version is compared against a [`ParsedVersion`] the same way pacman does:
+/// a missing epoch is the same as `0`, the upstream versions are compared with
+/// [`ValidUpstreamVersion::cmp`], and the releases are only compared when the constraint has one.
+/// In other words, a constraint without a release is equal to every release of its upstream version.
+///
+/// ```
+/// # use arch_pkg_text::value::Version;
+/// let constraint = Version("3.12").parse_constraint().unwrap();
+/// let version = |raw| Version(raw).parse().unwrap();
+/// assert!(constraint == version("3.12-1"));
+/// assert!(constraint == version("3.12-7.1"));
+/// assert!(constraint < version("3.12.1-1"));
+/// assert!(constraint > version("3.12rc1-1"));
+/// assert!(constraint < version("1:3.11-1"));
+///
+/// let constraint = Version("1:3.12-2").parse_constraint().unwrap();
+/// assert!(constraint > version("1:3.12-1"));
+/// assert!(constraint == version("1:3.12-2"));
+/// assert!(constraint < version("1:3.12-2.1"));
+/// ```
+///
+/// Since this relation is not transitive, [`ParsedConstraintVersion`] implements neither [`Ord`] nor [`Eq`].
+#[derive(Debug, Clone, Copy)]
+pub struct ParsedConstraintVersion<'a> {
+    epoch: Option<u64>,
+    upstream: ValidUpstreamVersion<'a>,
+    release: Option<ParsedRelease>,
+}
+
+impl<'a> ParsedConstraintVersion<'a> {
+    /// Construct a parsed constraint version.
+    pub fn new(
+        epoch: Option<u64>,
+        upstream: ValidUpstreamVersion<'a>,
+        release: Option<ParsedRelease>,
+    ) -> Self {
+        ParsedConstraintVersion {
+            epoch,
+            upstream,
+            release,
+        }
+    }
+
+    /// Extract the epoch, upstream version, and release respectively.
+    pub fn components(&self) -> (Option<u64>, ValidUpstreamVersion<'a>, Option<ParsedRelease>) {
+        let ParsedConstraintVersion {
+            epoch,
+            upstream,
+            release,
+        } = *self;
+        (epoch, upstream, release)
+    }
+
+    /// Compare a full version against the constraint version.
+    ///
+    /// The result is the [ordering](Ordering) of `version` relative to the constraint,
+    /// which makes it suitable for [`DependencySpecificationOperator::accepts`](super::DependencySpecificationOperator::accepts).
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::Version;
+    /// # use core::cmp::Ordering;
+    /// # use pretty_assertions::assert_eq;
+    /// let constraint = Version("2.39").parse_constraint().unwrap();
+    /// let version = Version("2.40-1").parse().unwrap();
+    /// assert_eq!(constraint.compare_version(&version), Ordering::Greater);
+    /// ```
+    pub fn compare_version(&self, version: &ParsedVersion<'_>) -> Ordering {
+        let (epoch, upstream, release) = version.components();
+        let ordering = epoch
+            .unwrap_or(0)
+            .cmp(&self.epoch.unwrap_or(0))
+            .then_with(|| upstream.cmp(&self.upstream));
+        match self.release {
+            Some(required) => ordering.then_with(|| release.cmp(&required)),
+            None => ordering,
+        }
+    }
+}
+
+impl<'a> From<ParsedVersion<'a>> for ParsedConstraintVersion<'a> {
+    fn from(version: ParsedVersion<'a>) -> Self {
+        let (epoch, upstream, release) = version.components();
+        ParsedConstraintVersion::new(epoch, upstream, Some(release))
+    }
+}
+
+impl<'a> PartialEq<ParsedVersion<'a>> for ParsedConstraintVersion<'_> {
+    /// Return `true` if [`ParsedConstraintVersion::compare_version`] returns [`Ordering::Equal`].
+    fn eq(&self, other: &ParsedVersion<'a>) -> bool {
+        self.compare_version(other) == Ordering::Equal
+    }
+}
+
+impl<'a> PartialEq<ParsedConstraintVersion<'a>> for ParsedVersion<'_> {
+    /// Return `true` if [`ParsedConstraintVersion::compare_version`] returns [`Ordering::Equal`].
+    fn eq(&self, other: &ParsedConstraintVersion<'a>) -> bool {
+        other == self
+    }
+}
+
+impl<'a> PartialOrd<ParsedVersion<'a>> for ParsedConstraintVersion<'_> {
+    /// Compare the constraint against a full version, see [`ParsedConstraintVersion::compare_version`].
+    fn partial_cmp(&self, other: &ParsedVersion<'a>) -> Option<Ordering> {
+        Some(self.compare_version(other).reverse())
+    }
+}
+
+impl<'a> PartialOrd<ParsedConstraintVersion<'a>> for ParsedVersion<'_> {
+    /// Compare a full version against the constraint, see [`ParsedConstraintVersion::compare_version`].
+    fn partial_cmp(&self, other: &ParsedConstraintVersion<'a>) -> Option<Ordering> {
+        Some(other.compare_version(self))
+    }
+}
+
+impl<'a> Version<'a> {
+    /// Parse and validate the version as a constraint version, in which the epoch and release are optional.
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::{DependencySpecification, ParsedRelease, Version};
+    /// # use pretty_assertions::assert_eq;
+    /// let (_, version) = DependencySpecification(">=2.39").components().unwrap();
+    /// let (epoch, upstream, release) = version.parse_constraint().unwrap().components();
+    /// assert_eq!((epoch, upstream.as_str(), release), (None, "2.39", None));
+    ///
+    /// let (epoch, upstream, release) = Version("1:2.39-1.1").parse_constraint().unwrap().components();
+    /// assert_eq!(
+    ///     (epoch, upstream.as_str(), release),
+    ///     (Some(1), "2.39", Some(ParsedRelease::new(1, Some(1)))),
+    /// );
+    /// ```
+    ///
+    /// The components must be valid when present:
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::{ParseVersionError, Version};
+    /// assert!(matches!(
+    ///     Version("a:2.39").parse_constraint(),
+    ///     Err(ParseVersionError::InvalidEpoch(_)),
+    /// ));
+    /// assert!(matches!(
+    ///     Version("2.39-").parse_constraint(),
+    ///     Err(ParseVersionError::InvalidRelease(_)),
+    /// ));
+    /// assert!(matches!(
+    ///     Version("2.39/1").parse_constraint(),
+    ///     Err(ParseVersionError::InvalidUpstream(_)),
+    /// ));
+    /// ```
+    pub fn parse_constraint(&self) -> Result<ParsedConstraintVersion<'a>, ParseVersionError<'a>> {
+        let (epoch, rest) = match self.split_once(':') {
+            Some((epoch, rest)) => (Some(Epoch(epoch)), rest),
+            None => (None, self.as_str()),
+        };
+        let (upstream, release) = match rest.rsplit_once('-') {
+            Some((upstream, release)) => (UpstreamVersion(upstream), Some(Release(release))),
+            None => (UpstreamVersion(rest), None),
+        };
+        let epoch = epoch
+            .as_ref()
+            .map(Epoch::parse)
+            .transpose()
+            .map_err(ParseVersionError::InvalidEpoch)?;
+        let upstream = upstream
+            .validate()
+            .map_err(ParseVersionError::InvalidUpstream)?;
+        let release = release
+            .as_ref()
+            .map(Release::parse)
+            .transpose()
+            .map_err(ParseVersionError::InvalidRelease)?;
+        Ok(ParsedConstraintVersion::new(epoch, upstream, release))
+    }
+}
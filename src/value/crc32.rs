@@ -0,0 +1,37 @@
+use super::{ParseArray, SkipOrArray, SkipOrCrc32};
+
+impl SkipOrCrc32<'_> {
+    /// Convert the decimal string into a 32-bit unsigned integer.
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::SkipOrCrc32;
+    /// assert_eq!(SkipOrCrc32("3733384285").u32(), Some(Some(3733384285)));
+    /// assert_eq!(SkipOrCrc32("SKIP").u32(), Some(None));
+    /// assert_eq!(SkipOrCrc32("0xDE87").u32(), None);
+    /// ```
+    pub fn u32(self) -> Option<Option<u32>> {
+        if self.as_str() == "SKIP" {
+            return Some(None);
+        }
+        if self.0.is_empty() || !self.0.bytes().all(|byte| byte.is_ascii_digit()) {
+            return None;
+        }
+        self.0.parse().ok().map(Some)
+    }
+
+    /// Convert the decimal string into an array of 8-bit unsigned integers in big-endian order.
+    pub fn u8_array(self) -> Option<SkipOrArray<4>> {
+        Some(match self.u32()? {
+            None => SkipOrArray::Skip,
+            Some(value) => SkipOrArray::Array(value.to_be_bytes()),
+        })
+    }
+}
+
+impl ParseArray for SkipOrCrc32<'_> {
+    type Array = SkipOrArray<4>;
+    type Error = ();
+    fn parse_array(&self) -> Result<Self::Array, Self::Error> {
+        self.u8_array().ok_or(())
+    }
+}
@@ -1,4 +1,7 @@
-use super::{Dependency, DependencyName, DependencySpecification};
+use super::{
+    Dependency, DependencyName, DependencySpecification, DependencySpecificationOperator, Name,
+    Version,
+};
 
 impl<'a> Dependency<'a> {
     /// Extract [`DependencyName`] and [`DependencySpecification`].
@@ -16,4 +19,88 @@ impl<'a> Dependency<'a> {
         let spec = DependencySpecification(spec);
         (name, spec)
     }
+
+    /// Check whether a provision (an item of `PROVIDES` or `provides`) satisfies the dependency.
+    ///
+    /// Versioned [soname](Dependency::soname) dependencies are only satisfied by soname provisions
+    /// (see [`ParsedSoname::satisfied_by`](super::ParsedSoname::satisfied_by)). Like alpm, an unversioned
+    /// soname dependency (e.g. `libfoo.so`) is matched by name alone.
+    ///
+    /// Otherwise, the names must be the same. A dependency without a specification is satisfied by
+    /// every provision of the name, a dependency with a specification is only satisfied by a provision
+    /// with an exact version (`=`) which [satisfies](DependencySpecification::satisfied_by) the specification.
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::Dependency;
+    /// assert!(Dependency("sh").satisfied_by_provision(&Dependency("sh")));
+    /// assert!(Dependency("sh").satisfied_by_provision(&Dependency("sh=5.2-1")));
+    /// assert!(Dependency("sh>=5").satisfied_by_provision(&Dependency("sh=5.2-1")));
+    /// assert!(!Dependency("sh>=6").satisfied_by_provision(&Dependency("sh=5.2-1")));
+    /// assert!(!Dependency("sh>=5").satisfied_by_provision(&Dependency("sh")));
+    /// assert!(!Dependency("sh").satisfied_by_provision(&Dependency("bash")));
+    /// assert!(Dependency("libfoo.so=1-64").satisfied_by_provision(&Dependency("libfoo.so=1-64")));
+    /// assert!(!Dependency("libfoo.so=1-64").satisfied_by_provision(&Dependency("libfoo.so=2-64")));
+    /// assert!(Dependency("libfoo.so").satisfied_by_provision(&Dependency("libfoo.so=1-64")));
+    /// assert!(Dependency("libfoo.so").satisfied_by_provision(&Dependency("libfoo.so=1")));
+    /// ```
+    pub fn satisfied_by_provision(&self, provision: &Dependency<'_>) -> bool {
+        match (self.soname(), provision.soname()) {
+            (Some(dependency), _) if dependency.soversion().is_none() => {
+                let provided_name = provision
+                    .split_once(['<', '>', '='])
+                    .map_or(provision.as_str(), |(name, _)| name);
+                return dependency.name().as_str() == provided_name;
+            }
+            (Some(dependency), Some(provision)) => return dependency.satisfied_by(&provision),
+            (Some(_), None) | (None, Some(_)) => return false,
+            (None, None) => {}
+        }
+        let (name, spec) = self.components();
+        let (provided_name, provided_spec) = provision.components();
+        if name.as_str() != provided_name.as_str() {
+            return false;
+        }
+        if spec.is_empty() {
+            return true;
+        }
+        match provided_spec.components() {
+            Some((DependencySpecificationOperator::Equal, provided_version)) => {
+                spec.satisfied_by(&provided_version)
+            }
+            _ => false,
+        }
+    }
+
+    /// Check whether a package of a name and a version satisfies the dependency by its name.
+    ///
+    /// The names must be the same, and the version must [satisfy](DependencySpecification::satisfied_by)
+    /// the specification. A package without a version only satisfies a dependency without a specification.
+    /// Versioned [soname](Dependency::soname) dependencies are never satisfied by package names,
+    /// unversioned ones are matched by name alone like other dependencies.
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::{Dependency, Name, Version};
+    /// let bash = (Name("bash"), Some(Version("5.2.037-1")));
+    /// assert!(Dependency("bash").satisfied_by_package(bash.0, bash.1));
+    /// assert!(Dependency("bash>=5").satisfied_by_package(bash.0, bash.1));
+    /// assert!(!Dependency("bash<5").satisfied_by_package(bash.0, bash.1));
+    /// assert!(!Dependency("sh").satisfied_by_package(bash.0, bash.1));
+    /// assert!(Dependency("bash").satisfied_by_package(bash.0, None));
+    /// assert!(!Dependency("bash>=5").satisfied_by_package(bash.0, None));
+    /// assert!(Dependency("libfoo.so").satisfied_by_package(Name("libfoo.so"), None));
+    /// assert!(!Dependency("libfoo.so=1-64").satisfied_by_package(Name("libfoo.so"), None));
+    /// ```
+    pub fn satisfied_by_package(&self, name: Name<'_>, version: Option<Version<'_>>) -> bool {
+        if let Some(soname) = self.soname() {
+            return soname.soversion().is_none() && soname.name().as_str() == name.as_str();
+        }
+        let (dependency_name, spec) = self.components();
+        if dependency_name.as_str() != name.as_str() {
+            return false;
+        }
+        match version {
+            Some(version) => spec.satisfied_by(&version),
+            None => spec.is_empty(),
+        }
+    }
 }
@@ -18,4 +18,39 @@ impl<'a> DependencySpecification<'a> {
         DependencySpecificationOperator::parse(self)
             .map(|(depend_spec_operator, version)| (depend_spec_operator, Version(version)))
     }
+
+    /// Check whether a candidate version satisfies the specification.
+    ///
+    /// The versions are compared the same way pacman does, with [`Version::vercmp`]:
+    /// the epoch is respected (a missing epoch is the same as `0`), and a specification
+    /// without a release (e.g. `=1.2`) accepts every release of the upstream version.
+    ///
+    /// An empty specification is satisfied by every version, a specification without a valid
+    /// operator is satisfied by none.
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::{DependencySpecification, Version};
+    /// let spec = DependencySpecification(">=1.2-3");
+    /// assert!(spec.satisfied_by(&Version("1.2-3")));
+    /// assert!(spec.satisfied_by(&Version("1.10-1")));
+    /// assert!(!spec.satisfied_by(&Version("1.2-2")));
+    ///
+    /// let spec = DependencySpecification("=1.2");
+    /// assert!(spec.satisfied_by(&Version("1.2-1")));
+    /// assert!(spec.satisfied_by(&Version("1.2-7")));
+    /// assert!(!spec.satisfied_by(&Version("1.2.1-1")));
+    ///
+    /// let spec = DependencySpecification("<2:0");
+    /// assert!(spec.satisfied_by(&Version("1:9.9-1")));
+    /// assert!(!spec.satisfied_by(&Version("2:0-1")));
+    ///
+    /// assert!(DependencySpecification("").satisfied_by(&Version("1.0-1")));
+    /// assert!(!DependencySpecification("~1.0").satisfied_by(&Version("1.0-1")));
+    /// ```
+    pub fn satisfied_by(&self, version: &Version<'_>) -> bool {
+        match self.components() {
+            Some((operator, required)) => operator.accepts(version.vercmp(&required)),
+            None => self.is_empty(),
+        }
+    }
 }
@@ -1,3 +1,4 @@
+use core::cmp::Ordering;
 use strum::{AsRefStr, Display, EnumString, IntoStaticStr};
 
 /// Operator at the start of a [`DependencySpecification`](super::DependencySpecification).
@@ -54,4 +55,27 @@ impl DependencySpecificationOperator {
                     .map(|rest| (candidate, rest))
             })
     }
+
+    /// Check whether the [ordering](Ordering) of a candidate version against the required version
+    /// is accepted by the operator.
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::DependencySpecificationOperator;
+    /// # use core::cmp::Ordering;
+    /// assert!(DependencySpecificationOperator::GreaterOrEqual.accepts(Ordering::Greater));
+    /// assert!(DependencySpecificationOperator::GreaterOrEqual.accepts(Ordering::Equal));
+    /// assert!(!DependencySpecificationOperator::GreaterOrEqual.accepts(Ordering::Less));
+    /// assert!(DependencySpecificationOperator::Less.accepts(Ordering::Less));
+    /// assert!(!DependencySpecificationOperator::Less.accepts(Ordering::Equal));
+    /// ```
+    pub fn accepts(self, ordering: Ordering) -> bool {
+        use DependencySpecificationOperator::*;
+        match self {
+            Less => ordering.is_lt(),
+            LessOrEqual => ordering.is_le(),
+            Equal => ordering.is_eq(),
+            GreaterOrEqual => ordering.is_ge(),
+            Greater => ordering.is_gt(),
+        }
+    }
 }
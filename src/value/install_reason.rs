@@ -0,0 +1,33 @@
+use super::InstallReason;
+use strum::{AsRefStr, Display, EnumString, IntoStaticStr};
+
+/// Reason for installing a package, parsed from [`InstallReason`].
+///
+/// A local package whose `desc` file doesn't have `%REASON%` was installed explicitly,
+/// which is why [`ParsedInstallReason::Explicit`] is the [default](Default).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)] // core traits
+#[derive(AsRefStr, Display, EnumString, IntoStaticStr)] // strum traits
+pub enum ParsedInstallReason {
+    /// The package was explicitly requested by the user.
+    #[default]
+    #[strum(serialize = "0")]
+    Explicit = 0,
+    /// The package was installed as a dependency of another package.
+    #[strum(serialize = "1")]
+    Dependency = 1,
+}
+
+impl InstallReason<'_> {
+    /// Parse the install reason.
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::{InstallReason, ParsedInstallReason};
+    /// # use pretty_assertions::assert_eq;
+    /// assert_eq!(InstallReason("0").parse(), Some(ParsedInstallReason::Explicit));
+    /// assert_eq!(InstallReason("1").parse(), Some(ParsedInstallReason::Dependency));
+    /// assert_eq!(InstallReason("2").parse(), None);
+    /// ```
+    pub fn parse(&self) -> Option<ParsedInstallReason> {
+        self.as_str().parse().ok()
+    }
+}
@@ -0,0 +1,121 @@
+use derive_more::{Display, Error};
+
+/// Error that occurs when decoding a base64 string.
+#[derive(Debug, Display, Error, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeBase64Error {
+    #[display("Invalid character {_1:?} at index {_0}")]
+    InvalidCharacter(#[error(not(source))] usize, char),
+    #[display("Invalid length")]
+    InvalidLength,
+    #[display("Invalid padding")]
+    InvalidPadding,
+    #[display("Output buffer is too small, {_0} bytes are required")]
+    OutputTooSmall(#[error(not(source))] usize),
+}
+
+/// Split a base64 string into its data and its padding.
+fn split_padding(input: &str) -> Result<&str, DecodeBase64Error> {
+    let data = input.trim_end_matches('=');
+    let padding = input.len() - data.len();
+    if padding == 0 {
+        return match data.len() % 4 {
+            1 => Err(DecodeBase64Error::InvalidLength),
+            _ => Ok(data),
+        };
+    }
+    if padding > 2 || input.len() % 4 != 0 {
+        return Err(DecodeBase64Error::InvalidPadding);
+    }
+    Ok(data)
+}
+
+/// Number of bytes encoded by a base64 string.
+pub(crate) fn decoded_len(input: &str) -> Result<usize, DecodeBase64Error> {
+    split_padding(input).map(|data| data.len() * 3 / 4)
+}
+
+/// Decode a base64 string (standard alphabet, padding is optional) into a buffer.
+///
+/// Return the number of bytes written.
+pub(crate) fn decode_base64(input: &str, output: &mut [u8]) -> Result<usize, DecodeBase64Error> {
+    let data = split_padding(input)?;
+    let len = data.len() * 3 / 4;
+    let output = output
+        .get_mut(..len)
+        .ok_or(DecodeBase64Error::OutputTooSmall(len))?;
+
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    let mut output_index = 0;
+    for (index, char) in data.char_indices() {
+        let value =
+            decode_base64_digit(char).ok_or(DecodeBase64Error::InvalidCharacter(index, char))?;
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            output[output_index] = (buffer >> bits) as u8;
+            output_index += 1;
+        }
+    }
+
+    debug_assert_eq!(output_index, len);
+    Ok(len)
+}
+
+fn decode_base64_digit(digit: char) -> Option<u8> {
+    let value = match digit {
+        'A'..='Z' => digit as u32 - 'A' as u32,
+        'a'..='z' => 26 + digit as u32 - 'a' as u32,
+        '0'..='9' => 52 + digit as u32 - '0' as u32,
+        '+' => 62,
+        '/' => 63,
+        _ => return None,
+    };
+    debug_assert!(value < 64);
+    Some(value as u8)
+}
+
+#[test]
+fn decode_base64_valid() {
+    use pretty_assertions::assert_eq;
+    let cases: &[(&str, &[u8])] = &[
+        ("", b""),
+        ("Zg==", b"f"),
+        ("Zm8=", b"fo"),
+        ("Zm9v", b"foo"),
+        ("Zm9vYg==", b"foob"),
+        ("Zm9vYmE=", b"fooba"),
+        ("Zm9vYmFy", b"foobar"),
+        ("Zm9vYg", b"foob"),
+        ("Zm9vYmE", b"fooba"),
+        ("+/+/", &[0xFB, 0xFF, 0xBF]),
+    ];
+    for (input, expected) in cases {
+        let mut output = [0; 8];
+        let len = decode_base64(input, &mut output).unwrap();
+        assert_eq!(&output[..len], *expected, "{input:?}");
+        assert_eq!(decoded_len(input), Ok(len), "{input:?}");
+    }
+}
+
+#[test]
+fn decode_base64_invalid() {
+    use pretty_assertions::assert_eq;
+    let cases = [
+        ("Zm9vY", DecodeBase64Error::InvalidLength),
+        ("Zm9vY===", DecodeBase64Error::InvalidPadding),
+        ("Zm9vYg=", DecodeBase64Error::InvalidPadding),
+        ("Zg=a", DecodeBase64Error::InvalidCharacter(2, '=')),
+        ("Zm9-", DecodeBase64Error::InvalidCharacter(3, '-')),
+        ("Zm9vYmFyYmF6", DecodeBase64Error::OutputTooSmall(9)),
+    ];
+    for (input, expected) in cases {
+        let mut output = [0; 8];
+        assert_eq!(
+            decode_base64(input, &mut output),
+            Err(expected),
+            "{input:?}"
+        );
+    }
+}
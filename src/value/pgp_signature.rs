@@ -0,0 +1,369 @@
+use super::{
+    PgpKey, PgpSignature,
+    parse_base64::{DecodeBase64Error, decode_base64, decoded_len},
+    parse_hex::ParseHex,
+};
+use derive_more::{Display, Error};
+
+impl PgpSignature<'_> {
+    /// Number of bytes encoded by the base64 string.
+    pub fn decoded_len(&self) -> Result<usize, DecodeBase64Error> {
+        decoded_len(self.as_str())
+    }
+
+    /// Decode the base64 string into a buffer, return the number of bytes written.
+    ///
+    /// The buffer must be at least [`decoded_len`](PgpSignature::decoded_len) bytes long.
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::PgpSignature;
+    /// # use pretty_assertions::assert_eq;
+    /// let mut buffer = [0; 8];
+    /// let len = PgpSignature("iAQE").decode_to_slice(&mut buffer).unwrap();
+    /// assert_eq!(&buffer[..len], &[0x88, 0x04, 0x04]);
+    /// ```
+    pub fn decode_to_slice(&self, output: &mut [u8]) -> Result<usize, DecodeBase64Error> {
+        decode_base64(self.as_str(), output)
+    }
+
+    /// Decode the base64 string into a vector of bytes.
+    #[cfg(feature = "std")]
+    pub fn decode(&self) -> Result<Vec<u8>, DecodeBase64Error> {
+        let mut output = vec![0; self.decoded_len()?];
+        self.decode_to_slice(&mut output)?;
+        Ok(output)
+    }
+
+    /// Decode the base64 string and [inspect](ParsedPgpSignature::parse_packet) the signature packet.
+    #[cfg(feature = "std")]
+    pub fn parse(&self) -> Result<ParsedPgpSignature, ParsePgpSignatureError> {
+        let bytes = self.decode().map_err(ParsePgpSignatureError::Decode)?;
+        ParsedPgpSignature::parse_packet(&bytes).map_err(ParsePgpSignatureError::Packet)
+    }
+}
+
+/// Error type of [`PgpSignature::parse`].
+#[cfg(feature = "std")]
+#[derive(Debug, Display, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ParsePgpSignatureError {
+    #[display("Failed to decode base64: {_0}")]
+    Decode(DecodeBase64Error),
+    #[display("Failed to parse signature packet: {_0}")]
+    Packet(ParsePgpSignaturePacketError),
+}
+
+/// Error type of [`ParsedPgpSignature::parse_packet`].
+#[derive(Debug, Display, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ParsePgpSignaturePacketError {
+    #[display("Unexpected end of input")]
+    UnexpectedEnd,
+    #[display("Invalid packet header: {_0:#04x}")]
+    InvalidHeader(#[error(not(source))] u8),
+    #[display("Partial body length is not supported")]
+    PartialBodyLength,
+    #[display("Packet of tag {_0} is not a signature")]
+    NotSignature(#[error(not(source))] u8),
+    #[display("Signature version {_0} is not supported")]
+    UnsupportedVersion(#[error(not(source))] u8),
+    #[display("Invalid length of the hashed material of a version 3 signature: {_0}")]
+    InvalidV3HashedLength(#[error(not(source))] u8),
+    #[display("Subpacket is empty")]
+    EmptySubpacket,
+}
+
+/// Hash algorithm of an OpenPGP signature.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PgpHashAlgorithm {
+    #[display("MD5")]
+    Md5,
+    #[display("SHA1")]
+    Sha1,
+    #[display("RIPEMD160")]
+    Ripemd160,
+    #[display("SHA256")]
+    Sha256,
+    #[display("SHA384")]
+    Sha384,
+    #[display("SHA512")]
+    Sha512,
+    #[display("SHA224")]
+    Sha224,
+    #[display("SHA3-256")]
+    Sha3_256,
+    #[display("SHA3-512")]
+    Sha3_512,
+    /// Algorithm ID which isn't recognized.
+    #[display("unknown ({_0})")]
+    Unknown(u8),
+}
+
+impl From<u8> for PgpHashAlgorithm {
+    fn from(id: u8) -> Self {
+        match id {
+            1 => PgpHashAlgorithm::Md5,
+            2 => PgpHashAlgorithm::Sha1,
+            3 => PgpHashAlgorithm::Ripemd160,
+            8 => PgpHashAlgorithm::Sha256,
+            9 => PgpHashAlgorithm::Sha384,
+            10 => PgpHashAlgorithm::Sha512,
+            11 => PgpHashAlgorithm::Sha224,
+            12 => PgpHashAlgorithm::Sha3_256,
+            14 => PgpHashAlgorithm::Sha3_512,
+            id => PgpHashAlgorithm::Unknown(id),
+        }
+    }
+}
+
+/// Fingerprint of the key which issued an OpenPGP signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PgpFingerprint {
+    /// Fingerprint of a version 4 key.
+    V4([u8; 20]),
+    /// Fingerprint of a version 5 key.
+    V5([u8; 32]),
+    /// Fingerprint of a version 6 key.
+    V6([u8; 32]),
+}
+
+impl PgpFingerprint {
+    /// Get the fingerprint as a slice of bytes.
+    pub fn as_slice(&self) -> &'_ [u8] {
+        match self {
+            PgpFingerprint::V4(bytes) => bytes,
+            PgpFingerprint::V5(bytes) | PgpFingerprint::V6(bytes) => bytes,
+        }
+    }
+
+    /// Derive the key ID from the fingerprint.
+    pub fn key_id(&self) -> [u8; 8] {
+        let bytes = self.as_slice();
+        let key_id = match self {
+            PgpFingerprint::V4(_) => &bytes[bytes.len() - 8..],
+            PgpFingerprint::V5(_) | PgpFingerprint::V6(_) => &bytes[..8],
+        };
+        key_id.try_into().expect("the key ID has 8 bytes")
+    }
+}
+
+/// Information of an OpenPGP signature packet, parsed from [`PgpSignature`].
+///
+/// Only the metadata of the signature is extracted, the signature itself is neither parsed nor verified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParsedPgpSignature {
+    /// Version of the signature packet (3, 4, or 6).
+    pub version: u8,
+    /// Type of the signature, `0x00` means a signature of a binary document.
+    pub signature_type: u8,
+    /// ID of the public-key algorithm.
+    pub public_key_algorithm: u8,
+    /// Hash algorithm of the signature.
+    pub hash_algorithm: PgpHashAlgorithm,
+    /// Creation time of the signature as a UNIX timestamp.
+    pub creation_time: Option<u32>,
+    /// Key ID of the issuer, as stated by the signature.
+    pub issuer_key_id: Option<[u8; 8]>,
+    /// Fingerprint of the issuer, as stated by the signature.
+    pub issuer_fingerprint: Option<PgpFingerprint>,
+}
+
+/// Cursor over the bytes of a packet.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], ParsePgpSignaturePacketError> {
+        if self.0.len() < len {
+            return Err(ParsePgpSignaturePacketError::UnexpectedEnd);
+        }
+        let (head, tail) = self.0.split_at(len);
+        self.0 = tail;
+        Ok(head)
+    }
+
+    fn array<const LEN: usize>(&mut self) -> Result<[u8; LEN], ParsePgpSignaturePacketError> {
+        self.take(LEN).map(|bytes| {
+            bytes
+                .try_into()
+                .expect("the slice has the length of the array")
+        })
+    }
+
+    fn u8(&mut self) -> Result<u8, ParsePgpSignaturePacketError> {
+        self.array().map(|[byte]| byte)
+    }
+
+    fn u16(&mut self) -> Result<u16, ParsePgpSignaturePacketError> {
+        self.array().map(u16::from_be_bytes)
+    }
+
+    fn u32(&mut self) -> Result<u32, ParsePgpSignaturePacketError> {
+        self.array().map(u32::from_be_bytes)
+    }
+
+    /// Read a length in the format of new packet headers and of subpackets.
+    fn variable_length(&mut self) -> Result<usize, ParsePgpSignaturePacketError> {
+        match self.u8()? {
+            first @ ..192 => Ok(first as usize),
+            first @ 192..224 => {
+                let second = self.u8()?;
+                Ok((((first as usize) - 192) << 8) + (second as usize) + 192)
+            }
+            255 => self.u32().map(|len| len as usize),
+            _ => Err(ParsePgpSignaturePacketError::PartialBodyLength),
+        }
+    }
+}
+
+impl ParsedPgpSignature {
+    /// Parse the metadata of a binary OpenPGP signature packet.
+    ///
+    /// Both the old and the new packet formats are supported, and so are version 3, 4, and 6 signatures.
+    pub fn parse_packet(bytes: &[u8]) -> Result<Self, ParsePgpSignaturePacketError> {
+        let mut reader = Reader(bytes);
+
+        let header = reader.u8()?;
+        if header & 0x80 == 0 {
+            return Err(ParsePgpSignaturePacketError::InvalidHeader(header));
+        }
+        let (tag, len) = if header & 0x40 != 0 {
+            (header & 0x3F, reader.variable_length()?)
+        } else {
+            let len = match header & 0x03 {
+                0 => reader.u8()? as usize,
+                1 => reader.u16()? as usize,
+                2 => reader.u32()? as usize,
+                _ => reader.0.len(), // indeterminate length, the packet extends to the end of input
+            };
+            ((header >> 2) & 0x0F, len)
+        };
+        if tag != 2 {
+            return Err(ParsePgpSignaturePacketError::NotSignature(tag));
+        }
+
+        let mut reader = Reader(reader.take(len)?);
+        let version = reader.u8()?;
+        if version == 3 {
+            let hashed_len = reader.u8()?;
+            if hashed_len != 5 {
+                return Err(ParsePgpSignaturePacketError::InvalidV3HashedLength(
+                    hashed_len,
+                ));
+            }
+            return Ok(ParsedPgpSignature {
+                version,
+                signature_type: reader.u8()?,
+                creation_time: Some(reader.u32()?),
+                issuer_key_id: Some(reader.array()?),
+                public_key_algorithm: reader.u8()?,
+                hash_algorithm: reader.u8()?.into(),
+                issuer_fingerprint: None,
+            });
+        }
+
+        let read_subpackets_len = match version {
+            4 => |reader: &mut Reader| reader.u16().map(usize::from),
+            6 => |reader: &mut Reader| reader.u32().map(|len| len as usize),
+            _ => return Err(ParsePgpSignaturePacketError::UnsupportedVersion(version)),
+        };
+        let mut signature = ParsedPgpSignature {
+            version,
+            signature_type: reader.u8()?,
+            public_key_algorithm: reader.u8()?,
+            hash_algorithm: reader.u8()?.into(),
+            creation_time: None,
+            issuer_key_id: None,
+            issuer_fingerprint: None,
+        };
+        let hashed_len = read_subpackets_len(&mut reader)?;
+        signature.read_subpackets(reader.take(hashed_len)?, true)?;
+        let unhashed_len = read_subpackets_len(&mut reader)?;
+        signature.read_subpackets(reader.take(unhashed_len)?, false)?;
+        Ok(signature)
+    }
+
+    /// Extract information from a subpacket area.
+    ///
+    /// The creation time is only trusted from the hashed area. The issuer may come from either area,
+    /// but the hashed area takes precedence.
+    fn read_subpackets(
+        &mut self,
+        area: &[u8],
+        hashed: bool,
+    ) -> Result<(), ParsePgpSignaturePacketError> {
+        let mut reader = Reader(area);
+        while !reader.0.is_empty() {
+            let len = reader.variable_length()?;
+            let mut subpacket = Reader(reader.take(len)?);
+            let kind = subpacket
+                .u8()
+                .map_err(|_| ParsePgpSignaturePacketError::EmptySubpacket)?
+                & 0x7F; // the highest bit is the critical flag
+            match (kind, subpacket.0.len()) {
+                (2, 4) if hashed => self.creation_time = Some(subpacket.u32()?),
+                (16, 8) if self.issuer_key_id.is_none() => {
+                    self.issuer_key_id = Some(subpacket.array()?);
+                }
+                (33, _) if self.issuer_fingerprint.is_none() => {
+                    self.issuer_fingerprint = match (subpacket.u8()?, subpacket.0.len()) {
+                        (4, 20) => Some(PgpFingerprint::V4(subpacket.array()?)),
+                        (5, 32) => Some(PgpFingerprint::V5(subpacket.array()?)),
+                        (6, 32) => Some(PgpFingerprint::V6(subpacket.array()?)),
+                        _ => None,
+                    };
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the key ID of the issuer, either stated directly or derived from the fingerprint.
+    pub fn key_id(&self) -> Option<[u8; 8]> {
+        self.issuer_key_id.or_else(|| {
+            self.issuer_fingerprint
+                .map(|fingerprint| fingerprint.key_id())
+        })
+    }
+
+    /// Check whether the signature was issued by a key.
+    ///
+    /// The key could either be a hexadecimal fingerprint (such as the values of `validpgpkeys`)
+    /// or a hexadecimal key ID. If the signature doesn't state the fingerprint of its issuer,
+    /// only the key IDs are compared.
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::{ParsedPgpSignature, PgpKey, PgpSignature};
+    /// let mut buffer = [0; 1024];
+    /// let len = PgpSignature(concat!(
+    ///     "iHUEABYKAB0WIQSDvIiJNRtd67toQW64rAhgDxCM3wUCZlKf1AAKCRC4rAhgDxCM319XAQDZW8vRCMsnOsn0GKvVAhNe",
+    ///     "oZW916fS87NpWeW/CLf3lgD/Y17FTUlh9CTXE/zg54ltntRedOrKXwgJ2zL3kd+mpA0=",
+    /// ))
+    /// .decode_to_slice(&mut buffer)
+    /// .unwrap();
+    /// let signature = ParsedPgpSignature::parse_packet(&buffer[..len]).unwrap();
+    /// assert!(signature.is_issued_by(PgpKey("83BC8889351B5DEBBB68416EB8AC08600F108CDF")));
+    /// assert!(signature.is_issued_by(PgpKey("b8ac08600f108cdf")));
+    /// assert!(!signature.is_issued_by(PgpKey("B9C0A582C442D58B787660A278F22916FCB4CCC7")));
+    /// ```
+    pub fn is_issued_by(&self, key: PgpKey) -> bool {
+        let key = key.as_str();
+
+        fn parse_hex<const LEN: usize>(hex: &str) -> Option<[u8; LEN]> {
+            let (invalid, array) = ParseHex::parse_hex(hex);
+            (hex.len() == LEN * 2 && invalid.is_empty()).then_some(array)
+        }
+
+        if let Some(key_id) = parse_hex::<8>(key) {
+            return self.key_id() == Some(key_id);
+        }
+
+        let fingerprint = match (parse_hex::<20>(key), parse_hex::<32>(key)) {
+            (Some(fingerprint), _) => PgpFingerprint::V4(fingerprint),
+            (None, Some(fingerprint)) => PgpFingerprint::V6(fingerprint),
+            (None, None) => return false,
+        };
+        match self.issuer_fingerprint {
+            Some(issuer_fingerprint) => issuer_fingerprint.as_slice() == fingerprint.as_slice(),
+            None => self.key_id() == Some(fingerprint.key_id()),
+        }
+    }
+}
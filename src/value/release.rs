@@ -0,0 +1,112 @@
+use super::Release;
+use core::{
+    fmt::{self, Display, Formatter},
+    num::ParseIntError,
+};
+use derive_more::{Display, Error};
+
+/// Package release, parsed from [`Release`].
+///
+/// A release consists of a major number and an optional minor number (also known as subrelease),
+/// e.g. `1` or `1.1`.
+///
+/// The releases are ordered the same way pacman orders them: the major numbers are compared first,
+/// then the minor numbers, and a release without a minor number is older than one with a minor number.
+///
+/// ```
+/// # use arch_pkg_text::value::Release;
+/// let parse = |raw| Release(raw).parse().unwrap();
+/// assert!(parse("1") < parse("1.1"));
+/// assert!(parse("1.1") < parse("1.2"));
+/// assert!(parse("1.9") < parse("1.10"));
+/// assert!(parse("1.10") < parse("2"));
+/// assert!(parse("1") < parse("1.0"));
+/// assert!(parse("1.01") == parse("1.1"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ParsedRelease {
+    major: u64,
+    minor: Option<u64>,
+}
+
+impl ParsedRelease {
+    /// Construct a parsed release.
+    pub fn new(major: u64, minor: Option<u64>) -> Self {
+        ParsedRelease { major, minor }
+    }
+
+    /// Extract the major and minor numbers respectively.
+    pub fn components(&self) -> (u64, Option<u64>) {
+        (self.major, self.minor)
+    }
+
+    /// Get the major number.
+    pub fn major(&self) -> u64 {
+        self.major
+    }
+
+    /// Get the minor number, if any.
+    pub fn minor(&self) -> Option<u64> {
+        self.minor
+    }
+}
+
+/// Create a release without a minor number.
+impl From<u64> for ParsedRelease {
+    fn from(major: u64) -> Self {
+        ParsedRelease::new(major, None)
+    }
+}
+
+/// Write the release in the same format as `pkgrel`.
+impl Display for ParsedRelease {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.minor {
+            None => write!(f, "{}", self.major),
+            Some(minor) => write!(f, "{}.{minor}", self.major),
+        }
+    }
+}
+
+/// Error type of [`Release::parse`].
+#[derive(Debug, Display, Clone, PartialEq, Eq, Error)]
+pub enum ParseReleaseError {
+    #[display("Invalid major number: {_0}")]
+    InvalidMajor(ParseIntError),
+    #[display("Invalid minor number: {_0}")]
+    InvalidMinor(ParseIntError),
+}
+
+impl Release<'_> {
+    /// Parse the release.
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::{ParsedRelease, Release};
+    /// # use pretty_assertions::assert_eq;
+    /// assert_eq!(Release("2").parse(), Ok(ParsedRelease::new(2, None)));
+    /// assert_eq!(Release("2.1").parse(), Ok(ParsedRelease::new(2, Some(1))));
+    /// ```
+    ///
+    /// Both numbers must be valid integers:
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::{ParseReleaseError, Release};
+    /// assert!(matches!(Release("").parse(), Err(ParseReleaseError::InvalidMajor(_))));
+    /// assert!(matches!(Release("a").parse(), Err(ParseReleaseError::InvalidMajor(_))));
+    /// assert!(matches!(Release("1.").parse(), Err(ParseReleaseError::InvalidMinor(_))));
+    /// assert!(matches!(Release("1.a").parse(), Err(ParseReleaseError::InvalidMinor(_))));
+    /// assert!(matches!(Release("1.1.1").parse(), Err(ParseReleaseError::InvalidMinor(_))));
+    /// ```
+    pub fn parse(&self) -> Result<ParsedRelease, ParseReleaseError> {
+        let (major, minor) = match self.split_once('.') {
+            Some((major, minor)) => (major, Some(minor)),
+            None => (self.as_str(), None),
+        };
+        let major = major.parse().map_err(ParseReleaseError::InvalidMajor)?;
+        let minor = minor
+            .map(str::parse)
+            .transpose()
+            .map_err(ParseReleaseError::InvalidMinor)?;
+        Ok(ParsedRelease::new(major, minor))
+    }
+}
@@ -0,0 +1,134 @@
+use super::{Dependency, DependencyName, vercmp::compare_version_strings};
+use derive_more::Display;
+
+/// ABI bitness of a [soname](ParsedSoname), which is the suffix of its version (e.g. `64` in `libfoo.so=1-64`).
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SonameBits {
+    #[display("32")]
+    Bits32,
+    #[display("64")]
+    Bits64,
+}
+
+impl SonameBits {
+    /// Parse the bitness from a string of either `32` or `64`.
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::SonameBits;
+    /// # use pretty_assertions::assert_eq;
+    /// assert_eq!(SonameBits::parse("32"), Some(SonameBits::Bits32));
+    /// assert_eq!(SonameBits::parse("64"), Some(SonameBits::Bits64));
+    /// assert_eq!(SonameBits::parse("16"), None);
+    /// ```
+    pub fn parse(input: &str) -> Option<Self> {
+        match input {
+            "32" => Some(SonameBits::Bits32),
+            "64" => Some(SonameBits::Bits64),
+            _ => None,
+        }
+    }
+}
+
+/// Soname dependency or provision of a shared library, parsed from [`Dependency`].
+///
+/// `makepkg` writes soname provisions in the form of `libfoo.so=<soversion>-<bits>`.
+/// Soname dependencies have either the same form or no version at all (`libfoo.so`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParsedSoname<'a> {
+    name: DependencyName<'a>,
+    version: Option<(&'a str, SonameBits)>,
+}
+
+impl<'a> ParsedSoname<'a> {
+    /// Construct a parsed soname.
+    pub fn new(name: DependencyName<'a>, version: Option<(&'a str, SonameBits)>) -> Self {
+        ParsedSoname { name, version }
+    }
+
+    /// Get the library name (e.g. `libfoo.so`).
+    pub fn name(&self) -> DependencyName<'a> {
+        self.name
+    }
+
+    /// Get the soversion (e.g. `1` in `libfoo.so=1-64`), if any.
+    pub fn soversion(&self) -> Option<&'a str> {
+        self.version.map(|(soversion, _)| soversion)
+    }
+
+    /// Get the ABI bitness (e.g. `64` in `libfoo.so=1-64`), if any.
+    pub fn bits(&self) -> Option<SonameBits> {
+        self.version.map(|(_, bits)| bits)
+    }
+
+    /// Check whether a soname provision satisfies the soname dependency.
+    ///
+    /// The library names must be the same. A dependency without a version is satisfied by every
+    /// provision of the library, a dependency with a version requires the provision to have the same
+    /// soversion and bitness.
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::Dependency;
+    /// let soname = |raw| Dependency(raw).soname().unwrap();
+    /// assert!(soname("libfoo.so=1-64").satisfied_by(&soname("libfoo.so=1-64")));
+    /// assert!(soname("libfoo.so").satisfied_by(&soname("libfoo.so=1-64")));
+    /// assert!(!soname("libfoo.so=1-64").satisfied_by(&soname("libfoo.so=2-64")));
+    /// assert!(!soname("libfoo.so=1-64").satisfied_by(&soname("libfoo.so=1-32")));
+    /// assert!(!soname("libfoo.so=1-64").satisfied_by(&soname("libfoo.so")));
+    /// assert!(!soname("libfoo.so=1-64").satisfied_by(&soname("libbar.so=1-64")));
+    /// ```
+    pub fn satisfied_by(&self, provision: &ParsedSoname<'_>) -> bool {
+        if self.name.as_str() != provision.name.as_str() {
+            return false;
+        }
+        match (self.version, provision.version) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some((required, required_bits)), Some((provided, provided_bits))) => {
+                required_bits == provided_bits
+                    && compare_version_strings(required, provided).is_eq()
+            }
+        }
+    }
+}
+
+impl<'a> Dependency<'a> {
+    /// Parse the dependency as a [soname](ParsedSoname), return `None` if it isn't one.
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::{Dependency, DependencyName, SonameBits};
+    /// # use pretty_assertions::assert_eq;
+    /// let soname = Dependency("libalpm.so=15-64").soname().unwrap();
+    /// assert_eq!(soname.name(), DependencyName("libalpm.so"));
+    /// assert_eq!(soname.soversion(), Some("15"));
+    /// assert_eq!(soname.bits(), Some(SonameBits::Bits64));
+    ///
+    /// let soname = Dependency("libalpm.so").soname().unwrap();
+    /// assert_eq!(soname.name(), DependencyName("libalpm.so"));
+    /// assert_eq!(soname.soversion(), None);
+    ///
+    /// assert!(Dependency("pacman=7.0.0-1").soname().is_none());
+    /// assert!(Dependency("libalpm.so>=15-64").soname().is_none());
+    /// assert!(Dependency("libalpm.so=15").soname().is_none());
+    /// ```
+    pub fn soname(&self) -> Option<ParsedSoname<'a>> {
+        let (name, version) = match self.split_once('=') {
+            Some((name, version)) => (name, Some(version)),
+            None => (self.as_str(), None),
+        };
+        let is_valid_char = |char: char| char.is_ascii_alphanumeric() || "@._+-".contains(char);
+        if !name.ends_with(".so") || !name.chars().all(is_valid_char) || name.starts_with('.') {
+            return None;
+        }
+        let version = match version {
+            None => None,
+            Some(version) => {
+                let (soversion, bits) = version.rsplit_once('-')?;
+                if soversion.is_empty() {
+                    return None;
+                }
+                Some((soversion, SonameBits::parse(bits)?))
+            }
+        };
+        Some(ParsedSoname::new(DependencyName(name), version))
+    }
+}
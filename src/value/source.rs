@@ -0,0 +1,298 @@
+use super::{FileName, Source};
+use core::fmt::{self, Formatter};
+use strum::{AsRefStr, Display, EnumString, IntoStaticStr};
+
+/// Version control system of a [source](ParsedSource), determined by its protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)] // core traits
+#[derive(AsRefStr, Display, EnumString, IntoStaticStr)] // strum traits
+pub enum SourceVcs {
+    #[strum(serialize = "bzr")]
+    Bazaar,
+    #[strum(serialize = "fossil")]
+    Fossil,
+    #[strum(serialize = "git")]
+    Git,
+    #[strum(serialize = "hg")]
+    Mercurial,
+    #[strum(serialize = "svn")]
+    Subversion,
+}
+
+/// Reference of a VCS [source](ParsedSource) to check out, which is the fragment after `#`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SourceFragment<'a> {
+    /// `#branch=<name>`.
+    Branch(&'a str),
+    /// `#bookmark=<name>`.
+    Bookmark(&'a str),
+    /// `#commit=<hash>`.
+    Commit(&'a str),
+    /// `#revision=<revision>`.
+    Revision(&'a str),
+    /// `#tag=<name>`.
+    Tag(&'a str),
+    /// Fragment of an unrecognized kind, such as `#foo=bar` or `#foo` (whose value is empty).
+    Other { kind: &'a str, value: &'a str },
+}
+
+impl<'a> SourceFragment<'a> {
+    /// Parse the fragment (without `#`).
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::SourceFragment;
+    /// # use pretty_assertions::assert_eq;
+    /// assert_eq!(SourceFragment::parse("tag=v1.0.0"), SourceFragment::Tag("v1.0.0"));
+    /// assert_eq!(SourceFragment::parse("commit=abc123"), SourceFragment::Commit("abc123"));
+    /// assert_eq!(
+    ///     SourceFragment::parse("foo"),
+    ///     SourceFragment::Other { kind: "foo", value: "" },
+    /// );
+    /// ```
+    pub fn parse(fragment: &'a str) -> Self {
+        let (kind, value) = fragment.split_once('=').unwrap_or((fragment, ""));
+        match kind {
+            "branch" => SourceFragment::Branch(value),
+            "bookmark" => SourceFragment::Bookmark(value),
+            "commit" => SourceFragment::Commit(value),
+            "revision" => SourceFragment::Revision(value),
+            "tag" => SourceFragment::Tag(value),
+            kind => SourceFragment::Other { kind, value },
+        }
+    }
+
+    /// Get the kind of the fragment (e.g. `tag` in `#tag=v1.0.0`).
+    pub fn kind(&self) -> &'a str {
+        match self {
+            SourceFragment::Branch(_) => "branch",
+            SourceFragment::Bookmark(_) => "bookmark",
+            SourceFragment::Commit(_) => "commit",
+            SourceFragment::Revision(_) => "revision",
+            SourceFragment::Tag(_) => "tag",
+            SourceFragment::Other { kind, .. } => kind,
+        }
+    }
+
+    /// Get the value of the fragment (e.g. `v1.0.0` in `#tag=v1.0.0`).
+    pub fn value(&self) -> &'a str {
+        match *self {
+            SourceFragment::Branch(value)
+            | SourceFragment::Bookmark(value)
+            | SourceFragment::Commit(value)
+            | SourceFragment::Revision(value)
+            | SourceFragment::Tag(value)
+            | SourceFragment::Other { value, .. } => value,
+        }
+    }
+}
+
+/// Entry of `source`, parsed from [`Source`] the way `makepkg` interprets it.
+///
+/// A source has the form of `[<file name>::]<location>`, the location being either a local file
+/// or a URL. The protocol of a URL may have a VCS prefix (e.g. `git+https://`), in which case
+/// the location may be followed by a query (`?signed`) and a fragment (e.g. `#tag=v1.0.0`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParsedSource<'a> {
+    rename: Option<&'a str>,
+    location: &'a str,
+    protocol: &'a str,
+    vcs: Option<SourceVcs>,
+    query: Option<&'a str>,
+    fragment: Option<&'a str>,
+}
+
+/// Name of the file or the directory in which `makepkg` stores a source, see [`ParsedSource::file_name`].
+///
+/// It consists of a [`FileName`] borrowed from the source entry and a suffix appended by `makepkg`
+/// (`.fossil` for fossil), which may be empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SourceFileName<'a> {
+    stem: FileName<'a>,
+    suffix: &'static str,
+}
+
+impl<'a> SourceFileName<'a> {
+    /// Get the part of the name which was taken from the source entry.
+    pub fn stem(&self) -> FileName<'a> {
+        self.stem
+    }
+
+    /// Get the suffix appended by `makepkg`, which is empty for most sources.
+    pub fn suffix(&self) -> &'static str {
+        self.suffix
+    }
+}
+
+impl fmt::Display for SourceFileName<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.stem, self.suffix)
+    }
+}
+
+impl PartialEq<str> for SourceFileName<'_> {
+    fn eq(&self, other: &str) -> bool {
+        other.strip_suffix(self.suffix) == Some(self.stem.as_str())
+    }
+}
+
+impl PartialEq<&str> for SourceFileName<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl<'a> Source<'a> {
+    /// Parse the source entry.
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::{Source, SourceFragment, SourceVcs};
+    /// # use pretty_assertions::assert_eq;
+    /// let source = Source("foo-1.0.tar.gz::https://example.com/archive/v1.0.tar.gz").parse();
+    /// assert_eq!(source.file_name(), "foo-1.0.tar.gz");
+    /// assert_eq!(source.protocol(), "https");
+    /// assert_eq!(source.url(), "https://example.com/archive/v1.0.tar.gz");
+    /// assert_eq!(source.vcs(), None);
+    ///
+    /// let source = Source("git+https://github.com/foo/bar.git?signed#tag=v1.0").parse();
+    /// assert_eq!(source.file_name(), "bar");
+    /// assert_eq!(source.protocol(), "git");
+    /// assert_eq!(source.url(), "https://github.com/foo/bar.git");
+    /// assert_eq!(source.vcs(), Some(SourceVcs::Git));
+    /// assert_eq!(source.fragment(), Some(SourceFragment::Tag("v1.0")));
+    /// assert!(source.is_signed());
+    ///
+    /// let source = Source("foo.patch").parse();
+    /// assert_eq!(source.file_name(), "foo.patch");
+    /// assert!(source.is_local());
+    /// ```
+    pub fn parse(&self) -> ParsedSource<'a> {
+        let (rename, location) = match self.as_str().split_once("::") {
+            Some((rename, location)) => (Some(rename), location),
+            None => (None, self.as_str()),
+        };
+
+        let protocol = match location.split_once("://") {
+            Some((scheme, _)) => scheme.split_once('+').map_or(scheme, |(vcs, _)| vcs),
+            None => match location.split_once("+lp:") {
+                Some((vcs, _)) => vcs,
+                None => "local",
+            },
+        };
+        let vcs = protocol.parse::<SourceVcs>().ok();
+
+        let (location, query, fragment) = match vcs {
+            None => (location, None, None),
+            Some(_) => {
+                // like makepkg, the query and the fragment may come in either order
+                let fragment = location.split_once('#').map(|(_, fragment)| {
+                    fragment
+                        .rsplit_once('?')
+                        .map_or(fragment, |(fragment, _)| fragment)
+                });
+                let query = location
+                    .split_once('?')
+                    .map(|(_, query)| query.split_once('#').map_or(query, |(query, _)| query));
+                let location = location
+                    .split_once(['#', '?'])
+                    .map_or(location, |(location, _)| location);
+                (location, query, fragment)
+            }
+        };
+
+        ParsedSource {
+            rename,
+            location,
+            protocol,
+            vcs,
+            query,
+            fragment,
+        }
+    }
+}
+
+impl<'a> ParsedSource<'a> {
+    /// Get the file name before `::`, if any.
+    pub fn rename(&self) -> Option<FileName<'a>> {
+        self.rename.map(FileName)
+    }
+
+    /// Get the name of the file or the directory in which `makepkg` stores the source.
+    ///
+    /// It starts from the [rename](ParsedSource::rename) if there is one, or the last path component of the
+    /// location otherwise. The name of a VCS source doesn't include the query, the fragment, anything from the
+    /// first `.git` onward (for git), or the `lp:` prefix (for bzr), and ends with `.fossil` (for fossil),
+    /// whether it comes from the rename or from the location.
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::Source;
+    /// # use pretty_assertions::assert_eq;
+    /// let source = Source("fossil+https://fossil.example.com/foo").parse();
+    /// assert_eq!(source.file_name(), "foo.fossil");
+    /// assert_eq!(source.file_name().stem().as_str(), "foo");
+    /// assert_eq!(source.file_name().suffix(), ".fossil");
+    ///
+    /// let source = Source("bar::fossil+https://fossil.example.com/foo").parse();
+    /// assert_eq!(source.file_name(), "bar.fossil");
+    /// ```
+    pub fn file_name(&self) -> SourceFileName<'a> {
+        let file_name = |stem, suffix| SourceFileName {
+            stem: FileName(stem),
+            suffix,
+        };
+        let name = self.rename.unwrap_or(self.location).trim_end_matches('/');
+        let name = name.rsplit_once('/').map_or(name, |(_, name)| name);
+        match self.vcs {
+            Some(SourceVcs::Git) => {
+                file_name(name.split_once(".git").map_or(name, |(name, _)| name), "")
+            }
+            Some(SourceVcs::Bazaar) => {
+                file_name(name.rsplit_once("lp:").map_or(name, |(_, name)| name), "")
+            }
+            Some(SourceVcs::Fossil) => file_name(name, ".fossil"),
+            _ => file_name(name, ""),
+        }
+    }
+
+    /// Get the protocol, such as `https`, `git` (for `git+https://`), or `local` (for local files).
+    pub fn protocol(&self) -> &'a str {
+        self.protocol
+    }
+
+    /// Check whether the source is a local file.
+    pub fn is_local(&self) -> bool {
+        self.protocol == "local"
+    }
+
+    /// Get the version control system, return `None` if the source is not a VCS checkout.
+    pub fn vcs(&self) -> Option<SourceVcs> {
+        self.vcs
+    }
+
+    /// Get the location to download or check out from.
+    ///
+    /// The VCS prefix (e.g. `git+`), the query, and the fragment of a VCS source are not included.
+    pub fn url(&self) -> &'a str {
+        let Some(vcs) = self.vcs else {
+            return self.location;
+        };
+        let prefix: &str = vcs.into();
+        self.location
+            .strip_prefix(prefix)
+            .and_then(|location| location.strip_prefix('+'))
+            .unwrap_or(self.location)
+    }
+
+    /// Get the query after `?` of a VCS source, if any.
+    pub fn query(&self) -> Option<&'a str> {
+        self.query
+    }
+
+    /// Check whether the query of a VCS source requests the signature of the reference to be verified (`?signed`).
+    pub fn is_signed(&self) -> bool {
+        self.query == Some("signed")
+    }
+
+    /// Get the reference to check out of a VCS source, if any.
+    pub fn fragment(&self) -> Option<SourceFragment<'a>> {
+        self.fragment.map(SourceFragment::parse)
+    }
+}
@@ -1,4 +1,7 @@
-use super::UpstreamVersion;
+use super::{
+    UpstreamVersion,
+    vercmp::{compare_version_strings, hash_version_string},
+};
 use core::{
     cmp::Ordering,
     hash::{Hash, Hasher},
@@ -126,7 +129,7 @@ impl<'a> ValidUpstreamVersion<'a> {
 impl Ord for ValidUpstreamVersion<'_> {
     /// Comparing two validated upstream versions.
     ///
-    /// This comparison aims to emulate [`vercmp`](https://man.archlinux.org/man/vercmp.8.en)'s
+    /// This comparison follows [`vercmp`](https://man.archlinux.org/man/vercmp.8.en)'s
     /// algorithm on validated upstream versions.
     ///
     /// ```
@@ -160,11 +163,23 @@ impl Ord for ValidUpstreamVersion<'_> {
     /// assert!(validate("1@2.0") < validate("1.2_3"));
     /// assert!(validate("1_1.0") > validate("1+1"));
     ///
+    /// // But the number of consecutive separators matters
+    /// assert!(validate("1..2") > validate("1.2"));
+    /// assert!(validate("2___a") > validate("2_a"));
+    ///
     /// // Leading zeros are ignored
     /// assert!(validate("01.02.3") == validate("1.2.03"));
     /// assert!(validate("1.02.0") < validate("1.2.3"));
     /// assert!(validate("1.01.0") > validate("1.1"));
     /// assert!(validate("1.1.0") > validate("1.001"));
+    ///
+    /// // Letters are older than numbers, and a trailing letter means a pre-release
+    /// assert!(validate("1.0alpha") < validate("1.0beta"));
+    /// assert!(validate("1.0rc1") < validate("1.0"));
+    /// assert!(validate("1.0a") < validate("1.0"));
+    /// assert!(validate("1.0a") < validate("1.0.a"));
+    /// assert!(validate("1.0.a") > validate("1.0"));
+    /// assert!(validate("1.0.a") < validate("1.0.1"));
     /// ```
     ///
     /// **NOTE:** For licensing reason, this trait method was implemented from scratch by testing
@@ -173,7 +188,7 @@ impl Ord for ValidUpstreamVersion<'_> {
     /// Contributors are welcomed to propose PRs to fix these edge-cases as long as they don't look
     /// at the source code of `vercmp`.
     fn cmp(&self, other: &Self) -> Ordering {
-        self.components().cmp(other.components())
+        compare_version_strings(self.as_str(), other.as_str())
     }
 }
 
@@ -191,8 +206,8 @@ impl PartialEq for ValidUpstreamVersion<'_> {
     /// Otherwise, return `false`.
     ///
     /// **NOTE:** Two versions being equal doesn't necessarily means that their internal
-    /// strings are equal. This is because dots (`.`), underscores (`_`), plus signs (`+`),
-    /// and at signs (`@`) were ignored during parsing.
+    /// strings are equal. This is because the kinds of separators (such as dots (`.`) and
+    /// underscores (`_`)) as well as leading zeros are ignored during comparison.
     fn eq(&self, other: &Self) -> bool {
         self.cmp(other) == Ordering::Equal
     }
@@ -202,9 +217,7 @@ impl Hash for ValidUpstreamVersion<'_> {
     /// This custom hash algorithm was implemented in such a way to be consistent with [`ValidUpstreamVersion::cmp`]
     /// and [`ValidUpstreamVersion::eq`].
     fn hash<State: Hasher>(&self, state: &mut State) {
-        for component in self.components() {
-            component.hash(state);
-        }
+        hash_version_string(self.as_str(), state);
     }
 }
 
@@ -0,0 +1,37 @@
+use super::Validation;
+use strum::{AsRefStr, Display, EnumString, IntoStaticStr};
+
+/// Method that was used to validate a package, parsed from [`Validation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)] // core traits
+#[derive(AsRefStr, Display, EnumString, IntoStaticStr)] // strum traits
+pub enum ParsedValidation {
+    /// The package was not validated.
+    #[strum(serialize = "none")]
+    None,
+    /// The package was validated with its MD5 checksum.
+    #[strum(serialize = "md5")]
+    Md5,
+    /// The package was validated with its SHA-256 checksum.
+    #[strum(serialize = "sha256")]
+    Sha256,
+    /// The package was validated with its PGP signature.
+    #[strum(serialize = "pgp")]
+    Pgp,
+}
+
+impl Validation<'_> {
+    /// Parse the validation method.
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::{ParsedValidation, Validation};
+    /// # use pretty_assertions::assert_eq;
+    /// assert_eq!(Validation("none").parse(), Some(ParsedValidation::None));
+    /// assert_eq!(Validation("md5").parse(), Some(ParsedValidation::Md5));
+    /// assert_eq!(Validation("sha256").parse(), Some(ParsedValidation::Sha256));
+    /// assert_eq!(Validation("pgp").parse(), Some(ParsedValidation::Pgp));
+    /// assert_eq!(Validation("crc").parse(), None);
+    /// ```
+    pub fn parse(&self) -> Option<ParsedValidation> {
+        self.as_str().parse().ok()
+    }
+}
@@ -0,0 +1,113 @@
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+/// Whether a character separates segments of a version string.
+fn is_separator(char: char) -> bool {
+    !char.is_ascii_alphanumeric()
+}
+
+/// Split off the leading separators, return their length in bytes and the rest.
+fn skip_separators(text: &str) -> (usize, &str) {
+    let rest = text.trim_start_matches(is_separator);
+    (text.len() - rest.len(), rest)
+}
+
+/// Split off the leading segment, which is either a run of digits or a run of letters.
+fn split_segment(text: &str, numeric: bool) -> (&str, &str) {
+    let is_segment_char = |char: char| match numeric {
+        true => char.is_ascii_digit(),
+        false => char.is_ascii_alphabetic(),
+    };
+    let boundary = text
+        .find(|char| !is_segment_char(char))
+        .unwrap_or(text.len());
+    text.split_at(boundary)
+}
+
+/// Compare two numeric segments, leading zeros are ignored.
+fn compare_numbers(a: &str, b: &str) -> Ordering {
+    let a = a.trim_start_matches('0');
+    let b = b.trim_start_matches('0');
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// Compare two version strings (or parts thereof, such as epochs and releases) the way `vercmp` does.
+///
+/// The strings are split into alternating runs of digits and letters. All other characters are
+/// separators, which are only significant by their lengths. Corresponding segments are compared
+/// pairwise: numbers are compared numerically, letters are compared lexicographically, and a
+/// number is always newer than letters.
+///
+/// When one string runs out of segments, the other one is newer unless its remaining part starts
+/// with letters, so that `1.0alpha` and `1.0rc1` are older than `1.0` while `1.0.1` and `1.0.a` are newer.
+pub(crate) fn compare_version_strings(a: &str, b: &str) -> Ordering {
+    if a == b {
+        return Ordering::Equal;
+    }
+
+    let (mut a, mut b) = (a, b);
+    while !a.is_empty() && !b.is_empty() {
+        let (a_separator, a_rest) = skip_separators(a);
+        let (b_separator, b_rest) = skip_separators(b);
+        (a, b) = (a_rest, b_rest);
+        if a.is_empty() || b.is_empty() {
+            break;
+        }
+        if a_separator != b_separator {
+            return a_separator.cmp(&b_separator);
+        }
+
+        let numeric = a.starts_with(|char: char| char.is_ascii_digit());
+        let (a_segment, a_rest) = split_segment(a, numeric);
+        let (b_segment, b_rest) = split_segment(b, numeric);
+        if b_segment.is_empty() {
+            return match numeric {
+                true => Ordering::Greater,
+                false => Ordering::Less,
+            };
+        }
+        let ordering = match numeric {
+            true => compare_numbers(a_segment, b_segment),
+            false => a_segment.cmp(b_segment),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+        (a, b) = (a_rest, b_rest);
+    }
+
+    let starts_with_letter = |text: &str| text.starts_with(|char: char| char.is_ascii_alphabetic());
+    match (a.is_empty(), b.is_empty()) {
+        (true, true) => Ordering::Equal,
+        (true, false) if starts_with_letter(b) => Ordering::Greater,
+        (true, false) => Ordering::Less,
+        (false, _) if starts_with_letter(a) => Ordering::Less,
+        (false, _) => Ordering::Greater,
+    }
+}
+
+/// Hash a version string in a way that is consistent with [`compare_version_strings`].
+///
+/// Two strings compare equal if and only if they have the same segments (ignoring leading zeros of
+/// numbers) preceded by separators of the same lengths, and either both or neither of them end with
+/// separators.
+pub(crate) fn hash_version_string<State: Hasher>(text: &str, state: &mut State) {
+    let mut text = text;
+    loop {
+        let (separator, rest) = skip_separators(text);
+        if rest.is_empty() {
+            (separator != 0).hash(state);
+            return;
+        }
+        let numeric = rest.starts_with(|char: char| char.is_ascii_digit());
+        let (segment, rest) = split_segment(rest, numeric);
+        let segment = match numeric {
+            true => segment.trim_start_matches('0'),
+            false => segment,
+        };
+        (separator, numeric, segment).hash(state);
+        text = rest;
+    }
+}
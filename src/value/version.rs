@@ -1,20 +1,30 @@
+use super::vercmp::compare_version_strings;
 use super::{
-    Epoch, Release, UpstreamVersion, ValidUpstreamVersion, ValidateUpstreamVersionError, Version,
+    Epoch, ParseReleaseError, ParsedRelease, Release, UpstreamVersion, ValidUpstreamVersion,
+    ValidateUpstreamVersionError, Version,
+};
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    num::ParseIntError,
 };
-use core::num::ParseIntError;
 use derive_more::{Display, Error};
 
 /// Result of [`Version::parse`].
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy)]
 pub struct ParsedVersion<'a> {
     epoch: Option<u64>,
     upstream: ValidUpstreamVersion<'a>,
-    release: u64,
+    release: ParsedRelease,
 }
 
 impl<'a> ParsedVersion<'a> {
     /// Construct a parsed version.
-    pub fn new(epoch: Option<u64>, upstream: ValidUpstreamVersion<'a>, release: u64) -> Self {
+    pub fn new(
+        epoch: Option<u64>,
+        upstream: ValidUpstreamVersion<'a>,
+        release: ParsedRelease,
+    ) -> Self {
         ParsedVersion {
             epoch,
             upstream,
@@ -23,7 +33,7 @@ impl<'a> ParsedVersion<'a> {
     }
 
     /// Extract the epoch, upstream version, and release respectively.
-    pub fn components(&self) -> (Option<u64>, ValidUpstreamVersion<'a>, u64) {
+    pub fn components(&self) -> (Option<u64>, ValidUpstreamVersion<'a>, ParsedRelease) {
         let ParsedVersion {
             epoch,
             upstream,
@@ -33,6 +43,58 @@ impl<'a> ParsedVersion<'a> {
     }
 }
 
+impl Ord for ParsedVersion<'_> {
+    /// Compare two parsed versions the same way [`vercmp`](https://man.archlinux.org/man/vercmp.8.en) does.
+    ///
+    /// The epochs are compared first (a missing epoch is the same as `0`), then the
+    /// [upstream versions](ValidUpstreamVersion::cmp), then the releases.
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::Version;
+    /// let parse = |raw| Version(raw).parse().unwrap();
+    /// assert!(parse("1.0-1") == parse("0:1.0-1"));
+    /// assert!(parse("1:1.0-1") > parse("2.0-1"));
+    /// assert!(parse("1.0rc1-1") < parse("1.0-1"));
+    /// assert!(parse("1.0-2") > parse("1.0-1"));
+    /// assert!(parse("1.0-1.1") > parse("1.0-1"));
+    /// assert!(parse("1.0-1.1") < parse("1.0-2"));
+    /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        let epoch = |version: &ParsedVersion| version.epoch.unwrap_or(0);
+        epoch(self)
+            .cmp(&epoch(other))
+            .then_with(|| self.upstream.cmp(&other.upstream))
+            .then_with(|| self.release.cmp(&other.release))
+    }
+}
+
+impl PartialOrd for ParsedVersion<'_> {
+    /// Return a `Some(ordering)` with `ordering` being the result of [`ParsedVersion::cmp`].
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Eq for ParsedVersion<'_> {}
+
+impl PartialEq for ParsedVersion<'_> {
+    /// Return `true` if [`ParsedVersion::cmp`] returns [`Ordering::Equal`].
+    /// Otherwise, return `false`.
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Hash for ParsedVersion<'_> {
+    /// This custom hash algorithm was implemented in such a way to be consistent with [`ParsedVersion::cmp`]
+    /// and [`ParsedVersion::eq`].
+    fn hash<State: Hasher>(&self, state: &mut State) {
+        self.epoch.unwrap_or(0).hash(state);
+        self.upstream.hash(state);
+        self.release.hash(state);
+    }
+}
+
 /// Error type of [`Version::components`].
 #[derive(Debug, Display, Clone, Copy, Error)]
 pub enum SplitVersionError {
@@ -50,7 +112,7 @@ pub enum ParseVersionError<'a> {
     #[display("Invalid upstream version: {_0}")]
     InvalidUpstream(#[error(not(source))] ValidateUpstreamVersionError<'a>),
     #[display("Invalid release: {_0}")]
-    InvalidRelease(ParseIntError),
+    InvalidRelease(ParseReleaseError),
 }
 
 /// Result of [`Version::components`].
@@ -114,27 +176,36 @@ impl<'a> Version<'a> {
     /// A valid version usually contains an epoch, an upstream version, and a release suffix:
     ///
     /// ```
-    /// # use arch_pkg_text::value::Version;
+    /// # use arch_pkg_text::value::{ParsedRelease, Version};
     /// # use pretty_assertions::assert_eq;
     /// let (epoch, upstream, release) = Version("2:0.1.2_rc.1-1").parse().unwrap().components();
     /// assert_eq!(
     ///     (epoch, upstream.as_str(), release),
-    ///     (Some(2), "0.1.2_rc.1", 1)
+    ///     (Some(2), "0.1.2_rc.1", ParsedRelease::new(1, None))
     /// );
     /// ```
     ///
     /// Epoch is optional:
     ///
     /// ```
-    /// # use arch_pkg_text::value::Version;
+    /// # use arch_pkg_text::value::{ParsedRelease, Version};
     /// # use pretty_assertions::assert_eq;
     /// let (epoch, upstream, release) = Version("0.1.2_rc.1-1").parse().unwrap().components();
     /// assert_eq!(
     ///     (epoch, upstream.as_str(), release),
-    ///     (None, "0.1.2_rc.1", 1)
+    ///     (None, "0.1.2_rc.1", ParsedRelease::new(1, None))
     /// );
     /// ```
     ///
+    /// Release may have a minor number (subrelease):
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::{ParsedRelease, Version};
+    /// # use pretty_assertions::assert_eq;
+    /// let (_, _, release) = Version("0.1.2-1.1").parse().unwrap().components();
+    /// assert_eq!(release, ParsedRelease::new(1, Some(1)));
+    /// ```
+    ///
     /// Release is mandatory:
     ///
     /// ```
@@ -171,7 +242,7 @@ impl<'a> Version<'a> {
     /// );
     /// ```
     ///
-    /// Release must be a valid integer:
+    /// Release must be a valid integer, optionally followed by a dot and another integer:
     ///
     /// ```
     /// # use arch_pkg_text::value::{ParseVersionError, Version};
@@ -199,6 +270,62 @@ impl<'a> Version<'a> {
     }
 }
 
+impl Version<'_> {
+    /// Compare two versions the same way [`vercmp`](https://man.archlinux.org/man/vercmp.8.en) does.
+    ///
+    /// Unlike [`Version::parse`], this method never fails: the versions are not validated,
+    /// the epoch defaults to `0`, and the releases are only compared when both versions have them.
+    ///
+    /// Because a version without a release is equal to every version with the same epoch and upstream
+    /// version, this relation is not transitive, which is why [`Version`] does not implement [`Ord`].
+    /// Use [`ParsedVersion`] for a total order.
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::Version;
+    /// # use core::cmp::Ordering;
+    /// # use pretty_assertions::assert_eq;
+    /// assert_eq!(Version("1.0-1").vercmp(&Version("1.0-2")), Ordering::Less);
+    /// assert_eq!(Version("1.0").vercmp(&Version("1.0-2")), Ordering::Equal);
+    /// assert_eq!(Version("0:1.0").vercmp(&Version("1.0")), Ordering::Equal);
+    /// assert_eq!(Version("1.0alpha").vercmp(&Version("1.0")), Ordering::Less);
+    /// assert_eq!(Version("1:0.1").vercmp(&Version("2.0")), Ordering::Greater);
+    /// ```
+    pub fn vercmp(&self, other: &Version<'_>) -> Ordering {
+        if self.as_str() == other.as_str() {
+            return Ordering::Equal;
+        }
+        let (self_epoch, self_upstream, self_release) = split_for_vercmp(self.as_str());
+        let (other_epoch, other_upstream, other_release) = split_for_vercmp(other.as_str());
+        let ordering = compare_version_strings(self_epoch, other_epoch)
+            .then_with(|| compare_version_strings(self_upstream, other_upstream));
+        match (self_release, other_release) {
+            (Some(self_release), Some(other_release)) => {
+                ordering.then_with(|| compare_version_strings(self_release, other_release))
+            }
+            _ => ordering,
+        }
+    }
+}
+
+/// Split a version string into epoch, upstream version, and optional release the same way `vercmp` does.
+///
+/// The epoch is only recognized when the version starts with digits followed by a colon,
+/// it defaults to `0` when missing or empty.
+fn split_for_vercmp(version: &str) -> (&str, &str, Option<&str>) {
+    let digits = version
+        .find(|char: char| !char.is_ascii_digit())
+        .unwrap_or(version.len());
+    let (epoch, rest) = match version[digits..].strip_prefix(':') {
+        Some(rest) => (&version[..digits], rest),
+        None => ("0", version),
+    };
+    let epoch = if epoch.is_empty() { "0" } else { epoch };
+    match rest.rsplit_once('-') {
+        Some((upstream, release)) => (epoch, upstream, Some(release)),
+        None => (epoch, rest, None),
+    }
+}
+
 impl<'a> TryFrom<Version<'a>> for ParsedVersion<'a> {
     type Error = ParseVersionError<'a>;
     fn try_from(version: Version<'a>) -> Result<Self, Self::Error> {
@@ -0,0 +1,21 @@
+use super::{XData, XDataKey, XDataValue};
+
+impl<'a> XData<'a> {
+    /// Extract [`XDataKey`] and [`XDataValue`].
+    ///
+    /// Return `None` if the entry doesn't have the form of `key=value`.
+    ///
+    /// ```
+    /// # use arch_pkg_text::value::{XData, XDataKey, XDataValue};
+    /// # use pretty_assertions::assert_eq;
+    /// let xdata = XData("pkgtype=split");
+    /// let (key, value) = xdata.components().unwrap();
+    /// assert_eq!(key, XDataKey("pkgtype"));
+    /// assert_eq!(value, XDataValue("split"));
+    /// assert_eq!(XData("pkgtype").components(), None);
+    /// ```
+    pub fn components(&self) -> Option<(XDataKey<'a>, XDataValue<'a>)> {
+        let (key, value) = self.as_str().split_once('=')?;
+        Some((XDataKey(key), XDataValue(value)))
+    }
+}
@@ -0,0 +1,274 @@
+#![cfg(feature = "std")]
+
+use arch_pkg_text::{
+    parse::ParsedSrcinfo,
+    resolve::{BuildPlan, BuildPlanner},
+    value::{Architecture, Base},
+};
+use pretty_assertions::assert_eq;
+
+fn parse<'a>(texts: &[&'a str]) -> Vec<ParsedSrcinfo<'a>> {
+    texts
+        .iter()
+        .map(|text| ParsedSrcinfo::parse(text).try_into_complete().unwrap())
+        .collect()
+}
+
+fn plan<'a>(architecture: &'a str, srcinfos: &[ParsedSrcinfo<'a>]) -> BuildPlan<'a> {
+    BuildPlanner::new(Architecture(architecture), srcinfos).plan()
+}
+
+const APP: &str = concat!(
+    "pkgbase = app\n",
+    "\tpkgver = 1.0\n",
+    "\tpkgrel = 1\n",
+    "\tarch = x86_64\n",
+    "\tarch = aarch64\n",
+    "\tmakedepends = rust\n",
+    "\tcheckdepends = test-runner\n",
+    "\tdepends = libfoo.so=1-64\n",
+    "\tdepends_aarch64 = arm-helper\n",
+    "\n",
+    "pkgname = app\n",
+);
+
+const FOO: &str = concat!(
+    "pkgbase = foo\n",
+    "\tpkgver = 1.2.3\n",
+    "\tpkgrel = 1\n",
+    "\tarch = x86_64\n",
+    "\tmakedepends = cmake\n",
+    "\n",
+    "pkgname = foo\n",
+    "\tprovides = libfoo.so=1-64\n",
+    "\n",
+    "pkgname = foo-docs\n",
+);
+
+const RUST: &str = concat!(
+    "pkgbase = rust\n",
+    "\tpkgver = 1.80.0\n",
+    "\tpkgrel = 1\n",
+    "\tepoch = 1\n",
+    "\tarch = x86_64\n",
+    "\tprovides = cargo\n",
+    "\n",
+    "pkgname = rust\n",
+);
+
+const TEST_RUNNER: &str = concat!(
+    "pkgbase = test-runner\n",
+    "\tpkgver = 0.1\n",
+    "\tpkgrel = 1\n",
+    "\tarch = any\n",
+    "\tmakedepends = cargo\n",
+    "\n",
+    "pkgname = test-runner\n",
+);
+
+const ARM_HELPER: &str = concat!(
+    "pkgbase = arm-helper\n",
+    "\tpkgver = 0.1\n",
+    "\tpkgrel = 1\n",
+    "\tarch = aarch64\n",
+    "\n",
+    "pkgname = arm-helper\n",
+);
+
+const CYCLE_A: &str = concat!(
+    "pkgbase = cycle-a\n",
+    "\tpkgver = 1\n",
+    "\tpkgrel = 1\n",
+    "\tmakedepends = cycle-b\n",
+    "\n",
+    "pkgname = cycle-a\n",
+);
+
+const CYCLE_B: &str = concat!(
+    "pkgbase = cycle-b\n",
+    "\tpkgver = 1\n",
+    "\tpkgrel = 1\n",
+    "\tdepends = cycle-a\n",
+    "\tmakedepends = rust>=1:1.79\n",
+    "\n",
+    "pkgname = cycle-b\n",
+);
+
+const CYCLE_C: &str = concat!(
+    "pkgbase = cycle-c\n",
+    "\tpkgver = 1\n",
+    "\tpkgrel = 1\n",
+    "\tmakedepends = cycle-d-virtual\n",
+    "\n",
+    "pkgname = cycle-c\n",
+);
+
+const CYCLE_D: &str = concat!(
+    "pkgbase = cycle-d\n",
+    "\tpkgver = 1\n",
+    "\tpkgrel = 1\n",
+    "\tprovides = cycle-d-virtual\n",
+    "\tmakedepends = cycle-c\n",
+    "\n",
+    "pkgname = cycle-d\n",
+);
+
+const OVERRIDE: &str = concat!(
+    "pkgbase = override\n",
+    "\tpkgver = 1\n",
+    "\tpkgrel = 1\n",
+    "\tprovides = base-virtual\n",
+    "\n",
+    "pkgname = override\n",
+    "\tprovides = derivative-virtual\n",
+    "\n",
+    "pkgname = override-inherit\n",
+);
+
+const NEEDS_BASE_VIRTUAL: &str = concat!(
+    "pkgbase = needs-base-virtual\n",
+    "\tpkgver = 1\n",
+    "\tpkgrel = 1\n",
+    "\tmakedepends = base-virtual\n",
+    "\n",
+    "pkgname = needs-base-virtual\n",
+);
+
+const NEEDS_DERIVATIVE_VIRTUAL: &str = concat!(
+    "pkgbase = needs-derivative-virtual\n",
+    "\tpkgver = 1\n",
+    "\tpkgrel = 1\n",
+    "\tmakedepends = derivative-virtual\n",
+    "\n",
+    "pkgname = needs-derivative-virtual\n",
+);
+
+const BLOCKED: &str = concat!(
+    "pkgbase = blocked\n",
+    "\tpkgver = 1\n",
+    "\tpkgrel = 1\n",
+    "\tmakedepends = cycle-b\n",
+    "\n",
+    "pkgname = blocked\n",
+);
+
+#[test]
+fn batches() {
+    let srcinfos = parse(&[APP, FOO, RUST, TEST_RUNNER, ARM_HELPER]);
+    assert_eq!(
+        plan("x86_64", &srcinfos),
+        BuildPlan {
+            batches: vec![
+                vec![Base("foo"), Base("rust"), Base("arm-helper")],
+                vec![Base("test-runner")],
+                vec![Base("app")],
+            ],
+            cycles: Vec::new(),
+            blocked: Vec::new(),
+        },
+    );
+}
+
+#[test]
+fn architecture_filter() {
+    let srcinfos = parse(&[APP, FOO, RUST, TEST_RUNNER, ARM_HELPER]);
+    assert_eq!(
+        plan("aarch64", &srcinfos).batches,
+        [
+            vec![Base("foo"), Base("rust"), Base("arm-helper")],
+            vec![Base("test-runner")],
+            vec![Base("app")],
+        ],
+    );
+    let srcinfos = parse(&[APP, ARM_HELPER]);
+    assert_eq!(
+        plan("x86_64", &srcinfos).batches,
+        [vec![Base("app"), Base("arm-helper")]],
+    );
+    assert_eq!(
+        plan("aarch64", &srcinfos).batches,
+        [vec![Base("arm-helper")], vec![Base("app")]],
+    );
+}
+
+#[test]
+fn derivative_provides_override_base_provides() {
+    let srcinfos = parse(&[NEEDS_BASE_VIRTUAL, NEEDS_DERIVATIVE_VIRTUAL, OVERRIDE]);
+    assert_eq!(
+        plan("x86_64", &srcinfos).batches,
+        [
+            vec![Base("override")],
+            vec![Base("needs-base-virtual"), Base("needs-derivative-virtual")],
+        ],
+    );
+
+    let override_only = OVERRIDE.replace("\npkgname = override-inherit\n", "");
+    let srcinfos = parse(&[NEEDS_BASE_VIRTUAL, NEEDS_DERIVATIVE_VIRTUAL, &override_only]);
+    assert_eq!(
+        plan("x86_64", &srcinfos).batches,
+        [
+            vec![Base("needs-base-virtual"), Base("override")],
+            vec![Base("needs-derivative-virtual")],
+        ],
+    );
+}
+
+#[test]
+fn versioned_dependencies() {
+    let rust = RUST.replace("epoch = 1", "epoch = 0");
+    let srcinfos = parse(&[CYCLE_B, &rust]);
+    assert_eq!(
+        plan("x86_64", &srcinfos).batches,
+        [vec![Base("cycle-b"), Base("rust")]],
+    );
+}
+
+#[test]
+fn cycles() {
+    let srcinfos = parse(&[BLOCKED, CYCLE_A, RUST, CYCLE_B, APP]);
+    assert_eq!(
+        plan("x86_64", &srcinfos),
+        BuildPlan {
+            batches: vec![vec![Base("rust")], vec![Base("app")]],
+            cycles: vec![vec![Base("cycle-a"), Base("cycle-b")]],
+            blocked: vec![Base("blocked")],
+        },
+    );
+}
+
+#[test]
+fn cycle_through_provision() {
+    let srcinfos = parse(&[CYCLE_D, CYCLE_C]);
+    let plan = plan("x86_64", &srcinfos);
+    assert_eq!(plan.batches, Vec::<Vec<Base>>::new());
+    assert_eq!(plan.cycles, [vec![Base("cycle-d"), Base("cycle-c")]]);
+    assert_eq!(plan.blocked, []);
+}
+
+#[test]
+fn long_cycle() {
+    const LENGTH: usize = 1_000;
+    let texts: Vec<String> = (0..LENGTH)
+        .map(|index| {
+            format!(
+                "pkgbase = node-{index}\n\tpkgver = 1.0\n\tpkgrel = 1\n\tarch = x86_64\n\tdepends = node-{}\npkgname = node-{index}\n",
+                (index + 1) % LENGTH,
+            )
+        })
+        .collect();
+    let texts: Vec<&str> = texts.iter().map(String::as_str).collect();
+    let srcinfos = parse(&texts);
+    // a small stack, so that a recursive search for cycles would overflow it
+    let plan = std::thread::scope(|scope| {
+        std::thread::Builder::new()
+            .stack_size(64 * 1024)
+            .spawn_scoped(scope, || plan("x86_64", &srcinfos))
+            .unwrap()
+            .join()
+            .unwrap()
+    });
+    assert_eq!(plan.batches, Vec::<Vec<Base>>::new());
+    assert_eq!(plan.cycles.len(), 1);
+    assert_eq!(plan.cycles[0].len(), LENGTH);
+    assert_eq!(plan.blocked, []);
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "checksum")]
+
+use arch_pkg_text::srcinfo::{
+    ChecksumArray, ChecksumType, ChecksumValue,
+    verify::{Hasher, Verification, VerifyChecksumError},
+};
+use hex_lit::hex;
+use pretty_assertions::assert_eq;
+use std::{fs, io::Cursor};
+
+const DATA: &[u8] = b"hello world\n";
+
+fn checksum(checksum_type: ChecksumType) -> &'static str {
+    match checksum_type {
+        ChecksumType::Crc32 => "3733384285",
+        ChecksumType::Md5 => "6f5902ac237024bdd0c176cb93063dc4",
+        ChecksumType::Sha1 => "22596363b3de40b06f981fb85d82312e8c0ed511",
+        ChecksumType::Sha224 => "95041dd60ab08c0bf5636d50be85fe9790300f39eb84602858a9b430",
+        ChecksumType::Sha256 => "a948904f2f0f479b8f8197694b30184b0d2ed1c1cd2a1ec0fb85d299a192a447",
+        ChecksumType::Sha384 => concat!(
+            "6b3b69ff0a404f28d75e98a066d3fc64fffd9940870cc68b",
+            "ece28545b9a75086b343d7a1366838083e4b8f3ca6fd3c80",
+        ),
+        ChecksumType::Sha512 => concat!(
+            "db3974a97f2407b7cae1ae637c0030687a11913274d578492558e39c16c017de",
+            "84eacdc8c62fe34ee4e12b4b1428817f09b6a2760c3f8a664ceae94d2434a593",
+        ),
+        ChecksumType::Blake2b => concat!(
+            "fec91c70284c72d0d4e3684788a90de9338a5b2f47f01fedbe203cafd6870871",
+            "8ae5672d10eca804a8121904047d40d1d6cf11e7a76419357a9469af41f22d01",
+        ),
+    }
+}
+
+#[test]
+fn digest() {
+    for checksum_type in ChecksumType::all_types().copied() {
+        eprintln!("CASE: {checksum_type:?}");
+        let expected = ChecksumValue::new(checksum_type, checksum(checksum_type))
+            .u8_array()
+            .unwrap();
+        assert_eq!(checksum_type.digest(DATA), expected);
+        assert_eq!(
+            checksum_type.digest_reader(Cursor::new(DATA)).unwrap(),
+            expected,
+        );
+    }
+}
+
+#[test]
+fn hasher() {
+    let mut hasher = Hasher::new(ChecksumType::Sha256);
+    assert_eq!(hasher.checksum_type(), ChecksumType::Sha256);
+    hasher.update(b"hello ");
+    hasher.update(b"world\n");
+    assert_eq!(
+        hasher.finalize(),
+        ChecksumArray::Sha256(hex!(
+            "a948904f2f0f479b8f8197694b30184b0d2ed1c1cd2a1ec0fb85d299a192a447"
+        )),
+    );
+}
+
+#[test]
+fn verify() {
+    for checksum_type in ChecksumType::all_types().copied() {
+        eprintln!("CASE: {checksum_type:?}, passed");
+        let value = ChecksumValue::new(checksum_type, checksum(checksum_type));
+        assert_eq!(value.verify(DATA).unwrap(), Verification::Passed);
+
+        eprintln!("CASE: {checksum_type:?}, failed");
+        let Verification::Failed { expected, actual } = value.verify(b"goodbye world\n").unwrap()
+        else {
+            panic!("expecting a failure");
+        };
+        assert_eq!(Some(expected), value.u8_array());
+        assert_eq!(actual, checksum_type.digest(b"goodbye world\n"));
+
+        eprintln!("CASE: {checksum_type:?}, skipped");
+        let value = ChecksumValue::new(checksum_type, "SKIP");
+        assert_eq!(value.verify(DATA).unwrap(), Verification::Skipped);
+    }
+}
+
+#[test]
+fn verify_file() {
+    let path = std::env::temp_dir().join("arch-pkg-text-checksum-verification.txt");
+    fs::write(&path, DATA).unwrap();
+    let value = ChecksumValue::new(ChecksumType::Sha1, checksum(ChecksumType::Sha1));
+    let verification = value.verify_file(&path);
+    fs::remove_file(&path).unwrap();
+    assert_eq!(verification.unwrap(), Verification::Passed);
+}
+
+#[test]
+fn skip_does_not_open_file() {
+    let value = ChecksumValue::new(ChecksumType::Sha256, "SKIP");
+    let verification = value.verify_file("/path/that/does/not/exist").unwrap();
+    assert_eq!(verification, Verification::Skipped);
+    assert!(verification.is_accepted());
+}
+
+#[test]
+fn missing_file() {
+    let value = ChecksumValue::new(ChecksumType::Sha256, checksum(ChecksumType::Sha256));
+    let error = value.verify_file("/path/that/does/not/exist").unwrap_err();
+    assert!(matches!(error, VerifyChecksumError::Io(_)));
+}
+
+#[test]
+fn invalid_checksum() {
+    let value = ChecksumValue::new(ChecksumType::Md5, "006f5902ac237024bdd0c176cb93063dc4");
+    let error = value.verify(DATA).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Value of md5sums is not a valid checksum",
+    );
+}
@@ -0,0 +1,68 @@
+use arch_pkg_text::value::{Dependency, Version};
+use pretty_assertions::assert_eq;
+
+/// Dependencies, candidate versions, and whether the candidates satisfy the dependencies.
+const CASES: &[(&str, &str, bool)] = &[
+    // without specification
+    ("foo", "1.0-1", true),
+    ("foo", "1:0.1-1", true),
+    // equal, with release
+    ("foo=1.2-3", "1.2-3", true),
+    ("foo=1.2-3", "1.2-4", false),
+    ("foo=1.2-3", "1.2-3.1", false),
+    ("foo=1.2-3", "0:1.2-3", true),
+    ("foo=1.2-3", "1:1.2-3", false),
+    // equal, without release
+    ("foo=1.2", "1.2-1", true),
+    ("foo=1.2", "1.2-99", true),
+    ("foo=1.2", "1.2-1.1", true),
+    ("foo=1.2", "1.2.0-1", false),
+    ("foo=1.2", "1.2rc1-1", false),
+    ("foo=1.2", "1:1.2-1", false),
+    // equal, with epoch
+    ("foo=1:1.2", "1:1.2-1", true),
+    ("foo=1:1.2", "1.2-1", false),
+    ("foo=0:1.2", "1.2-1", true),
+    // greater or equal
+    ("foo>=1.2-3", "1.2-3", true),
+    ("foo>=1.2-3", "1.2-4", true),
+    ("foo>=1.2-3", "1.2-2", false),
+    ("foo>=1.2-3", "1.2-3.1", true),
+    ("foo>=1.2-3", "1.10-1", true),
+    ("foo>=1.2-3", "1.1-9", false),
+    ("foo>=1.2-3", "1:0.1-1", true),
+    ("foo>=1.2", "1.2-1", true),
+    ("foo>=1.2", "1.2rc1-1", false),
+    ("foo>=2:1.0", "1:99-1", false),
+    // greater
+    ("foo>1.2", "1.2-1", false),
+    ("foo>1.2", "1.2.1-1", true),
+    ("foo>1.2-3", "1.2-3", false),
+    ("foo>1.2-3", "1.2-3.1", true),
+    ("foo>1.2-3", "1.2-4", true),
+    // less or equal
+    ("foo<=1.2-3", "1.2-3", true),
+    ("foo<=1.2-3", "1.2-4", false),
+    ("foo<=1.2", "1.2-99", true),
+    ("foo<=1.2", "1.2.1-1", false),
+    ("foo<=1.2", "1.2rc1-1", true),
+    // less
+    ("foo<2:0", "1:9.9-1", true),
+    ("foo<2:0", "2:0-1", false),
+    ("foo<2:0", "99-1", true),
+    ("foo<1.2", "1.2-1", false),
+    ("foo<1.2", "1.2alpha-1", true),
+    ("foo<1.2-3", "1.2-2.9", true),
+    // sonames
+    ("libfoo.so=1-64", "1-64", true),
+    ("libfoo.so>=1-64", "2-64", true),
+];
+
+#[test]
+fn satisfied_by() {
+    for (dependency, version, expected) in CASES {
+        eprintln!("CASE: {dependency} {version} -> {expected}");
+        let (_, spec) = Dependency(dependency).components();
+        assert_eq!(spec.satisfied_by(&Version(version)), *expected);
+    }
+}
@@ -0,0 +1,146 @@
+use arch_pkg_text::{
+    desc::{FieldName, Query},
+    parse::ParsedDesc,
+    value::{Dependency, Name},
+};
+use pretty_assertions::assert_eq;
+
+/// Fixtures in the format of a sync database, whose fields are in the order `repo-add` writes them.
+const FIXTURES: &[(&str, &str)] = &[
+    (
+        "gnome-shell.desc",
+        include_str!("fixtures/gnome-shell.desc"),
+    ),
+    (
+        "sync-db/packages/foo-1.0-1/desc",
+        include_str!("fixtures/sync-db/packages/foo-1.0-1/desc"),
+    ),
+    (
+        "sync-db/packages/bar-2.0-1/desc",
+        include_str!("fixtures/sync-db/packages/bar-2.0-1/desc"),
+    ),
+];
+
+/// Fixtures in the format of the local database, whose fields are in the order pacman writes them.
+const LOCAL_FIXTURES: &[(&str, &str)] = &[
+    ("local-bash.desc", include_str!("fixtures/local-bash.desc")),
+    (
+        "local-db/valid/bash-5.2.037-1/desc",
+        include_str!("fixtures/local-db/valid/bash-5.2.037-1/desc"),
+    ),
+    (
+        "local-db/valid/foo-bar-2.0-3/desc",
+        include_str!("fixtures/local-db/valid/foo-bar-2.0-3/desc"),
+    ),
+    (
+        "local-db/unsupported-version/foo-1.0-1/desc",
+        include_str!("fixtures/local-db/unsupported-version/foo-1.0-1/desc"),
+    ),
+    (
+        "local-db/missing-version/foo-1.0-1/desc",
+        include_str!("fixtures/local-db/missing-version/foo-1.0-1/desc"),
+    ),
+];
+
+#[test]
+fn fixtures_are_written_verbatim() {
+    for (name, text) in FIXTURES {
+        eprintln!("CASE: {name}");
+        let written = ParsedDesc::parse(text).unwrap().to_string();
+        assert_eq!(&written, text);
+    }
+}
+
+#[test]
+fn round_trip() {
+    for (name, text) in FIXTURES.iter().chain(LOCAL_FIXTURES) {
+        eprintln!("CASE: {name}");
+        let parsed = ParsedDesc::parse(text).unwrap();
+        let written = parsed.to_string();
+        assert_eq!(ParsedDesc::parse(&written).unwrap(), parsed);
+    }
+}
+
+#[test]
+fn local_fields_are_written() {
+    let written = ParsedDesc::parse(include_str!("fixtures/local-bash.desc"))
+        .unwrap()
+        .to_string();
+    for field in [
+        "%INSTALLDATE%\n1734512345\n\n",
+        "%REASON%\n1\n\n",
+        "%VALIDATION%\nsha256\npgp\n\n",
+        "%DEPENDS%\nreadline\nlibreadline.so=8-64\nglibc\nncurses\n\n",
+        "%XDATA%\npkgtype=pkg\n\n",
+    ] {
+        eprintln!("CASE: {field:?}");
+        assert!(written.contains(field));
+    }
+}
+
+#[test]
+fn round_trip_unusual_format() {
+    let text = "%NAME%\r\nfoo\r\n%DEPENDS%\r\n\r\nbar\r\nbaz\r\n\r\n\r\n%VERSION%\n  1.0-1  \n";
+    let parsed = ParsedDesc::parse(text).unwrap();
+    let written = parsed.to_string();
+    assert_eq!(
+        written,
+        "%NAME%\nfoo\n\n%VERSION%\n1.0-1\n\n%DEPENDS%\nbar\r\nbaz\n\n",
+    );
+    assert_eq!(ParsedDesc::parse(&written).unwrap(), parsed);
+}
+
+#[test]
+fn build() {
+    let desc = ParsedDesc::default()
+        .with_raw_value(FieldName::MakeDependencies, "meson")
+        .with_raw_value(FieldName::Dependencies, "glibc\nbash")
+        .with_raw_value(FieldName::Provides, "sh")
+        .with_raw_value(FieldName::Version, "5.2.037-1")
+        .with_raw_value(FieldName::Name, "bash")
+        .with_raw_value(FieldName::Description, "to be removed")
+        .without_raw_value(FieldName::Description);
+    assert_eq!(desc.name(), Some(Name("bash")));
+    assert_eq!(desc.description(), None);
+
+    let written = desc.to_string();
+    assert_eq!(
+        written,
+        [
+            "%NAME%",
+            "bash",
+            "",
+            "%VERSION%",
+            "5.2.037-1",
+            "",
+            "%PROVIDES%",
+            "sh",
+            "",
+            "%DEPENDS%",
+            "glibc",
+            "bash",
+            "",
+            "%MAKEDEPENDS%",
+            "meson",
+            "",
+            "",
+        ]
+        .join("\n"),
+    );
+
+    let parsed = ParsedDesc::parse(&written).unwrap();
+    assert_eq!(parsed, desc);
+    assert_eq!(
+        parsed
+            .dependencies()
+            .unwrap()
+            .into_iter()
+            .collect::<Vec<_>>(),
+        [Dependency("glibc"), Dependency("bash")],
+    );
+}
+
+#[test]
+fn write_nothing() {
+    assert_eq!(ParsedDesc::default().to_string(), "");
+}
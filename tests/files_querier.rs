@@ -0,0 +1,79 @@
+use arch_pkg_text::{
+    files::{FieldName, ForgetfulQuerier, MemoQuerier, Query, QueryMut},
+    value::{BackupEntry, FilePath, Hex128},
+};
+use core::ops::Not;
+use hex_lit::hex;
+use pretty_assertions::assert_eq;
+
+const TEXT: &str = include_str!("fixtures/pacman.files");
+
+const FILES: &[FilePath] = &[
+    FilePath("etc/"),
+    FilePath("etc/makepkg.conf"),
+    FilePath("etc/pacman.conf"),
+    FilePath("usr/"),
+    FilePath("usr/bin/"),
+    FilePath("usr/bin/makepkg"),
+    FilePath("usr/bin/pacman"),
+];
+
+fn backup_components<'a>(
+    entries: impl IntoIterator<Item = BackupEntry<'a>>,
+) -> Vec<(FilePath<'a>, Option<[u8; 16]>)> {
+    entries
+        .into_iter()
+        .map(|entry| entry.components())
+        .map(|(path, checksum)| (path, checksum.and_then(Hex128::u8_array)))
+        .collect()
+}
+
+fn expected_backup() -> Vec<(FilePath<'static>, Option<[u8; 16]>)> {
+    vec![
+        (
+            FilePath("etc/makepkg.conf"),
+            Some(hex!("3c8b9a1b4a3d0f5e2c6b7d8e9f0a1b2c")),
+        ),
+        (
+            FilePath("etc/pacman.conf"),
+            Some(hex!("2a2ee1f6ec3ee3b7bc1c3d42e4ab3c7c")),
+        ),
+    ]
+}
+
+#[test]
+fn forgetful() {
+    let querier = ForgetfulQuerier::new(TEXT);
+    let files: Vec<_> = querier.files().unwrap().into_iter().collect();
+    assert_eq!(files, FILES);
+    assert_eq!(
+        backup_components(querier.backup().unwrap()),
+        expected_backup(),
+    );
+}
+
+#[test]
+fn memo() {
+    let mut querier = MemoQuerier::new(TEXT);
+    assert!(querier.__has_cache(FieldName::Files).not());
+    assert!(querier.__has_cache(FieldName::Backup).not());
+
+    let backup = querier.backup_mut().unwrap();
+    assert!(querier.__has_cache(FieldName::Files));
+    assert!(querier.__has_cache(FieldName::Backup));
+    assert_eq!(backup_components(backup), expected_backup());
+
+    let files: Vec<_> = querier.files_mut().unwrap().into_iter().collect();
+    assert_eq!(files, FILES);
+}
+
+#[test]
+fn without_backup() {
+    let text = "%FILES%\nusr/\nusr/bin/\nusr/bin/foo\n";
+    let querier = ForgetfulQuerier::new(text);
+    assert_eq!(querier.files().unwrap().into_iter().count(), 3);
+    assert!(querier.backup().is_none());
+    let mut querier = MemoQuerier::new(text);
+    assert!(querier.backup_mut().is_none());
+    assert_eq!(querier.files_mut().unwrap().into_iter().count(), 3);
+}
@@ -0,0 +1,106 @@
+#![cfg(feature = "std")]
+
+use arch_pkg_text::{
+    db::local::{LoadLocalDbError, LocalDb},
+    desc::Query as _,
+    files::Query as _,
+    parse::ParsedDesc,
+    value::{FilePath, Name},
+};
+use pretty_assertions::assert_eq;
+use std::io::ErrorKind;
+
+const BASH_DESC: &str = include_str!("fixtures/local-db/valid/bash-5.2.037-1/desc");
+const BASH_MTREE: &[u8] = include_bytes!("fixtures/local-db/valid/bash-5.2.037-1/mtree");
+
+#[test]
+fn open() {
+    let db = LocalDb::open("tests/fixtures/local-db/valid").unwrap();
+    assert_eq!(db.len(), 2);
+    assert_eq!(db.names().collect::<Vec<_>>(), ["bash", "foo-bar"]);
+
+    let bash = db.get("bash").unwrap().unwrap();
+    assert_eq!(bash.name, "bash");
+    assert_eq!(bash.directory, "bash-5.2.037-1");
+    assert_eq!(bash.desc, ParsedDesc::parse(BASH_DESC).unwrap());
+    assert_eq!(bash.mtree, Some(BASH_MTREE));
+    let backup: Vec<_> = bash
+        .files
+        .unwrap()
+        .backup()
+        .unwrap()
+        .into_iter()
+        .map(|entry| entry.components().0)
+        .collect();
+    assert_eq!(
+        backup,
+        [FilePath("etc/bash.bashrc"), FilePath("etc/skel/.bashrc")],
+    );
+
+    let foo_bar = db.get("foo-bar").unwrap().unwrap();
+    assert_eq!(foo_bar.directory, "foo-bar-2.0-3");
+    assert_eq!(foo_bar.desc.name(), Some(Name("foo-bar")));
+    assert!(foo_bar.desc.reason().is_none());
+    assert_eq!(foo_bar.mtree, None);
+    let files: Vec<_> = foo_bar
+        .files
+        .unwrap()
+        .files()
+        .unwrap()
+        .into_iter()
+        .collect();
+    assert_eq!(files.last(), Some(&FilePath("usr/share/foo-bar/README")));
+
+    assert!(db.get("foo").is_none());
+}
+
+#[test]
+fn packages() {
+    let db = LocalDb::open("tests/fixtures/local-db/valid").unwrap();
+    let packages: Vec<_> = db
+        .packages()
+        .map(Result::unwrap)
+        .map(|package| {
+            let version = package.desc.version().map(|version| version.as_str());
+            (package.name, version)
+        })
+        .collect();
+    assert_eq!(
+        packages,
+        [("bash", Some("5.2.037-1")), ("foo-bar", Some("2.0-3"))],
+    );
+}
+
+#[test]
+fn unsupported_version() {
+    let error = LocalDb::open("tests/fixtures/local-db/unsupported-version").unwrap_err();
+    assert!(
+        matches!(&error, LoadLocalDbError::UnsupportedVersion(version) if version == "8"),
+        "{error:?}",
+    );
+    assert_eq!(error.to_string(), r#"Unsupported database version: "8""#);
+}
+
+#[test]
+fn missing_version() {
+    let error = LocalDb::open("tests/fixtures/local-db/missing-version").unwrap_err();
+    assert!(
+        matches!(error, LoadLocalDbError::MissingVersion),
+        "{error:?}",
+    );
+    assert_eq!(error.to_string(), "ALPM_DB_VERSION not found");
+}
+
+#[test]
+fn missing_root() {
+    let error = LocalDb::open("tests/fixtures/local-db/does-not-exist").unwrap_err();
+    assert!(
+        matches!(
+            &error,
+            LoadLocalDbError::Io(path, source)
+            if path.as_os_str() == "tests/fixtures/local-db/does-not-exist"
+                && source.kind() == ErrorKind::NotFound
+        ),
+        "{error:?}",
+    );
+}
@@ -0,0 +1,103 @@
+use arch_pkg_text::{
+    desc::{FieldName, ForgetfulQuerier, MemoQuerier, Query, QueryMut},
+    parse::ParsedDesc,
+    value::{
+        Dependency, Name, ParsedInstallReason, ParsedValidation, Validation, XDataKey, XDataValue,
+    },
+};
+use pretty_assertions::assert_eq;
+
+const TEXT: &str = include_str!("fixtures/local-bash.desc");
+
+fn assert_query<'a>(querier: &impl Query<'a>) {
+    assert_eq!(querier.name(), Some(Name("bash")));
+    assert_eq!(querier.file_name(), None);
+
+    assert_eq!(querier.install_date().unwrap().parse(), Ok(1734512345));
+    assert_eq!(querier.size().unwrap().parse(), Ok(9262998));
+
+    assert_eq!(querier.reason().map(|reason| reason.as_str()), Some("1"));
+    assert_eq!(
+        querier.reason().unwrap().parse(),
+        Some(ParsedInstallReason::Dependency),
+    );
+
+    let validation: Vec<_> = querier.validation().unwrap().into_iter().collect();
+    assert_eq!(validation, [Validation("sha256"), Validation("pgp")]);
+    let validation: Vec<_> = validation.iter().map(Validation::parse).collect();
+    assert_eq!(
+        validation,
+        [Some(ParsedValidation::Sha256), Some(ParsedValidation::Pgp)],
+    );
+
+    let xdata: Vec<_> = querier
+        .xdata()
+        .unwrap()
+        .into_iter()
+        .map(|xdata| xdata.components())
+        .collect();
+    assert_eq!(xdata, [Some((XDataKey("pkgtype"), XDataValue("pkg")))]);
+
+    let dependencies: Vec<_> = querier.dependencies().unwrap().into_iter().collect();
+    assert_eq!(
+        dependencies,
+        [
+            Dependency("readline"),
+            Dependency("libreadline.so=8-64"),
+            Dependency("glibc"),
+            Dependency("ncurses"),
+        ],
+    );
+}
+
+#[test]
+fn parsed() {
+    let querier = ParsedDesc::parse(TEXT).unwrap();
+    dbg!(&querier);
+    assert_query(&querier);
+}
+
+#[test]
+fn forgetful() {
+    assert_query(&ForgetfulQuerier::new(TEXT));
+}
+
+#[test]
+fn memo() {
+    let mut querier = MemoQuerier::new(TEXT);
+    assert!(!querier.__has_cache(FieldName::XData));
+    assert_eq!(
+        querier.reason_mut().unwrap().parse(),
+        Some(ParsedInstallReason::Dependency),
+    );
+    assert!(querier.__has_cache(FieldName::Reason));
+    assert!(querier.__has_cache(FieldName::InstallDate));
+    assert!(!querier.__has_cache(FieldName::XData));
+    assert_eq!(querier.install_date_mut().unwrap().parse(), Ok(1734512345));
+    assert_eq!(querier.size_mut().unwrap().parse(), Ok(9262998));
+    let validation: Vec<_> = querier.validation_mut().unwrap().into_iter().collect();
+    assert_eq!(validation, [Validation("sha256"), Validation("pgp")]);
+    let xdata: Vec<_> = querier.xdata_mut().unwrap().into_iter().collect();
+    assert_eq!(xdata.len(), 1);
+    assert!(querier.__has_cache(FieldName::XData));
+}
+
+#[test]
+fn missing_reason_means_explicit() {
+    let querier = ParsedDesc::parse(TEXT)
+        .unwrap()
+        .without_raw_value(FieldName::Reason);
+    let reason = querier
+        .reason()
+        .map_or(Some(ParsedInstallReason::default()), |reason| {
+            reason.parse()
+        });
+    assert_eq!(reason, Some(ParsedInstallReason::Explicit));
+}
+
+#[test]
+fn round_trip() {
+    let parsed = ParsedDesc::parse(TEXT).unwrap();
+    let written = parsed.to_string();
+    assert_eq!(ParsedDesc::parse(&written).unwrap(), parsed);
+}
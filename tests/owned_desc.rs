@@ -0,0 +1,89 @@
+#![cfg(feature = "std")]
+
+use arch_pkg_text::{
+    desc::{FieldName, ParsedField, Query},
+    parse::{OwnedDesc, ParsedDesc},
+};
+use pretty_assertions::assert_eq;
+
+const FIXTURES: &[(&str, &str)] = &[
+    (
+        "gnome-shell.desc",
+        include_str!("fixtures/gnome-shell.desc"),
+    ),
+    ("local-bash.desc", include_str!("fixtures/local-bash.desc")),
+];
+
+const FIELD_NAMES: &[FieldName] = &[
+    FieldName::FileName,
+    FieldName::Name,
+    FieldName::Base,
+    FieldName::Version,
+    FieldName::Description,
+    FieldName::Groups,
+    FieldName::CompressedSize,
+    FieldName::InstalledSize,
+    FieldName::Md5Checksum,
+    FieldName::Sha256Checksum,
+    FieldName::PgpSignature,
+    FieldName::Url,
+    FieldName::License,
+    FieldName::Architecture,
+    FieldName::BuildDate,
+    FieldName::InstallDate,
+    FieldName::Packager,
+    FieldName::Size,
+    FieldName::Reason,
+    FieldName::Validation,
+    FieldName::Replaces,
+    FieldName::Conflicts,
+    FieldName::Provides,
+    FieldName::Dependencies,
+    FieldName::OptionalDependencies,
+    FieldName::MakeDependencies,
+    FieldName::CheckDependencies,
+    FieldName::XData,
+];
+
+#[test]
+fn same_as_parsed() {
+    for (name, text) in FIXTURES {
+        eprintln!("CASE: {name}");
+        let parsed = ParsedDesc::parse(text).unwrap();
+        let owned = OwnedDesc::from(parsed);
+        for field_name in FIELD_NAMES {
+            eprintln!("FIELD: {field_name}");
+            let field = ParsedField::new(*field_name);
+            assert_eq!((&owned).query_raw_text(field), parsed.query_raw_text(field));
+        }
+    }
+}
+
+#[test]
+fn round_trip() {
+    for (name, text) in FIXTURES {
+        eprintln!("CASE: {name}");
+        let parsed = ParsedDesc::parse(text).unwrap();
+        let owned = OwnedDesc::from(&parsed);
+        assert_eq!(owned.as_parsed(), parsed);
+        assert_eq!(ParsedDesc::from(&owned), parsed);
+        assert_eq!(owned.text(), parsed.to_string());
+        assert_eq!(owned.to_string(), parsed.to_string());
+    }
+}
+
+#[test]
+fn outlive_text() {
+    let owned = {
+        let text = FIXTURES[0].1.to_string();
+        OwnedDesc::from(ParsedDesc::parse(&text).unwrap())
+    };
+    assert_eq!(owned.as_parsed().name().unwrap().as_str(), "gnome-shell");
+}
+
+#[test]
+fn empty() {
+    let owned = OwnedDesc::from(ParsedDesc::default());
+    assert_eq!(owned.text(), "");
+    assert_eq!(owned.as_parsed(), ParsedDesc::default());
+}
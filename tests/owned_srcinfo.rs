@@ -0,0 +1,165 @@
+#![cfg(feature = "std")]
+
+pub mod _utils;
+pub use _utils::*;
+
+use arch_pkg_text::{
+    parse::{OwnedSrcinfo, ParsedSrcinfo},
+    srcinfo::{Checksums, ClearedFields, FieldName, Query, QueryRawTextItem},
+    value::Name,
+};
+use pretty_assertions::assert_eq;
+
+const FIXTURES: &[(&str, &str)] = &[
+    ("complex", COMPLEX),
+    ("simple", SIMPLE),
+    ("has-empty-values", HAS_EMPTY_VALUES),
+    ("multiple-checksum-types", MULTIPLE_CHECKSUM_TYPES),
+];
+
+const FIELD_NAMES: &[FieldName] = &[
+    FieldName::Base,
+    FieldName::Name,
+    FieldName::Epoch,
+    FieldName::Release,
+    FieldName::ValidPgpKeys,
+    FieldName::Version,
+    FieldName::Architecture,
+    FieldName::Backup,
+    FieldName::ChangeLog,
+    FieldName::Description,
+    FieldName::Groups,
+    FieldName::InstallScript,
+    FieldName::License,
+    FieldName::NoExtract,
+    FieldName::Options,
+    FieldName::Source,
+    FieldName::Url,
+    FieldName::Dependencies,
+    FieldName::CheckDependencies,
+    FieldName::MakeDependencies,
+    FieldName::OptionalDependencies,
+    FieldName::Provides,
+    FieldName::Conflicts,
+    FieldName::Replaces,
+    FieldName::Crc32Checksums,
+    FieldName::Md5Checksums,
+    FieldName::Sha1Checksums,
+    FieldName::Sha224Checksums,
+    FieldName::Sha256Checksums,
+    FieldName::Sha384Checksums,
+    FieldName::Sha512Checksums,
+    FieldName::Blake2bChecksums,
+];
+
+/// Query a field and sort the result so that queriers which order sections differently can be compared.
+fn query_sorted<'a>(querier: impl Query<'a>, field_name: FieldName) -> Vec<(String, &'a str)> {
+    let mut items: Vec<_> = querier
+        .query_raw_text(field_name)
+        .map(|item: QueryRawTextItem<'a>| {
+            let location = format!("{:?} {:?}", item.section, item.architecture);
+            (location, item.value)
+        })
+        .collect();
+    items.sort_by(|(a, _), (b, _)| a.cmp(b)); // stable sort, values within a location keep their order
+    items
+}
+
+/// Format a query item so that items of different queriers can be sorted and compared.
+fn debug(item: impl std::fmt::Debug) -> String {
+    format!("{item:?}")
+}
+
+#[test]
+fn same_as_parsed() {
+    for (name, text) in FIXTURES {
+        eprintln!("CASE: {name}");
+        let parsed = ParsedSrcinfo::parse(text).try_into_complete().unwrap();
+        let owned = OwnedSrcinfo::from(&parsed);
+        for field_name in FIELD_NAMES {
+            eprintln!("FIELD: {field_name}");
+            assert_eq!(
+                query_sorted(&owned, *field_name),
+                query_sorted(&parsed, *field_name),
+            );
+        }
+    }
+}
+
+#[test]
+fn checksums() {
+    for (name, text) in FIXTURES {
+        eprintln!("CASE: {name}");
+        let parsed = ParsedSrcinfo::parse(text).try_into_complete().unwrap();
+        let owned = OwnedSrcinfo::from(&parsed);
+        let mut owned_checksums: Vec<_> = (&owned).checksums().map(debug).collect();
+        let mut parsed_checksums: Vec<_> = parsed.checksums().map(debug).collect();
+        owned_checksums.sort();
+        parsed_checksums.sort();
+        assert_eq!(owned_checksums, parsed_checksums);
+    }
+}
+
+#[test]
+fn cleared_fields() {
+    let text = concat!(
+        "pkgbase = foo\n",
+        "\tpkgver = 1.0\n",
+        "\tpkgrel = 1\n",
+        "\tarch = x86_64\n",
+        "\tdepends = bar\n",
+        "\tdepends_x86_64 = baz\n",
+        "\n",
+        "pkgname = foo\n",
+        "\tdepends =\n",
+        "\tdepends_x86_64 =\n",
+        "\n",
+        "pkgname = foo-extra\n",
+        "\n",
+    );
+    let parsed = ParsedSrcinfo::parse(text).try_into_complete().unwrap();
+    let owned = OwnedSrcinfo::from(&parsed);
+    let parsed_cleared: Vec<_> = parsed.cleared_fields().map(debug).collect();
+    assert_eq!(parsed_cleared.len(), 2);
+    let owned_cleared: Vec<_> = (&owned).cleared_fields().map(debug).collect();
+    assert_eq!(owned_cleared, parsed_cleared);
+    let borrowed_cleared: Vec<_> = owned.as_parsed().cleared_fields().map(debug).collect();
+    assert_eq!(borrowed_cleared, parsed_cleared);
+    assert_eq!(
+        query_sorted(&owned, FieldName::Dependencies),
+        query_sorted(&parsed, FieldName::Dependencies),
+    );
+    assert_eq!(owned.text(), parsed.to_string());
+}
+
+#[test]
+fn round_trip() {
+    for (name, text) in FIXTURES {
+        eprintln!("CASE: {name}");
+        let parsed = ParsedSrcinfo::parse(text).try_into_complete().unwrap();
+        let owned = OwnedSrcinfo::from(&parsed);
+        assert_eq!(owned.text(), parsed.to_string());
+        assert_eq!(owned.to_string(), parsed.to_string());
+        let borrowed = ParsedSrcinfo::from(&owned);
+        assert_eq!(borrowed.to_string(), parsed.to_string());
+        assert_eq!(
+            borrowed.derivatives.keys().collect::<Vec<_>>(),
+            parsed.derivatives.keys().collect::<Vec<_>>(),
+        );
+        assert_eq!(OwnedSrcinfo::from(borrowed), owned);
+    }
+}
+
+#[test]
+fn outlive_text() {
+    let owned = {
+        let text = COMPLEX.to_string();
+        OwnedSrcinfo::from(ParsedSrcinfo::parse(&text).try_into_complete().unwrap())
+    };
+    let names: Vec<_> = (&owned).derivative_names().collect();
+    assert_eq!(names, [Name("foo-bin"), Name("bar-bin")]);
+    assert_eq!(
+        owned.as_parsed().base.base_name().map(|name| name.as_str()),
+        Some("complex-example-bin"),
+    );
+}
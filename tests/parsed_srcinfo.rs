@@ -10,8 +10,8 @@ use arch_pkg_text::{
     },
     srcinfo::{ChecksumArray, Checksums, Query, QueryItem, Section},
     value::{
-        Architecture, Base, Dependency, Description, License, Name, SkipOrArray, Source,
-        UpstreamVersion,
+        Architecture, Base, Dependency, Description, License, Name, ParsedRelease, SkipOrArray,
+        Source, UpstreamVersion,
     },
 };
 use hex_lit::hex;
@@ -52,7 +52,10 @@ fn assert_complex(querier: &ParsedSrcinfo) {
         base.version().as_ref().map(UpstreamVersion::as_str),
         Some("12.34.56.r789"),
     );
-    assert_eq!(base.release().unwrap().parse().ok(), Some(2));
+    assert_eq!(
+        base.release().unwrap().parse(),
+        Ok(ParsedRelease::new(2, None))
+    );
     assert_eq!(base.epoch().unwrap().parse().ok(), Some(3));
     assert_eq!(
         base.description(),
@@ -179,7 +182,10 @@ fn assert_complex(querier: &ParsedSrcinfo) {
         querier.version().as_ref().map(UpstreamVersion::as_str),
         Some("12.34.56.r789"),
     );
-    assert_eq!(querier.release().unwrap().parse().ok(), Some(2));
+    assert_eq!(
+        querier.release().unwrap().parse(),
+        Ok(ParsedRelease::new(2, None))
+    );
     assert_eq!(querier.epoch().unwrap().parse().ok(), Some(3));
     assert_eq!(
         querier
@@ -335,7 +341,10 @@ fn assert_simple(querier: &ParsedSrcinfo) {
         base.version().as_ref().map(UpstreamVersion::as_str),
         Some("12.34.56.r789"),
     );
-    assert_eq!(base.release().unwrap().parse().ok(), Some(1));
+    assert_eq!(
+        base.release().unwrap().parse(),
+        Ok(ParsedRelease::new(1, None))
+    );
     assert!(base.epoch().is_none());
     assert_eq!(
         base.description(),
@@ -387,7 +396,10 @@ fn assert_simple(querier: &ParsedSrcinfo) {
         querier.version().as_ref().map(UpstreamVersion::as_str),
         Some("12.34.56.r789"),
     );
-    assert_eq!(querier.release().unwrap().parse().ok(), Some(1));
+    assert_eq!(
+        querier.release().unwrap().parse(),
+        Ok(ParsedRelease::new(1, None))
+    );
     assert!(querier.epoch().is_none());
     assert_eq!(
         querier
@@ -0,0 +1,193 @@
+#![cfg(feature = "std")]
+
+use arch_pkg_text::{
+    desc::Query,
+    parse::ParsedDesc,
+    value::{
+        DecodeBase64Error, ParsePgpSignatureError, ParsePgpSignaturePacketError,
+        ParsedPgpSignature, PgpFingerprint, PgpHashAlgorithm, PgpKey, PgpSignature,
+    },
+};
+use hex_lit::hex;
+use pretty_assertions::assert_eq;
+
+const GNOME_SHELL: &str = include_str!("fixtures/gnome-shell.desc");
+
+/// Signature made by an Ed25519 key with `gpg --detach-sign --digest-algo sha256`.
+const ED25519: PgpSignature = PgpSignature(concat!(
+    "iIsEABYIADMWIQS5wKWCxELVi3h2YKJ48ikW/LTMxwUCatMj8xUccGFja2FnZXJAZXhhbXBsZS5jb20ACgkQePIpFvy0zMeG",
+    "lgD8CWjotvWdTtYfSJ/vpkusQ+aQVxmTewLlQ5++Et6D/ioBALo4pabPuy519MY12PGA6MO4froEi3jUrILBDePIjwIK",
+));
+
+/// Signature made by an RSA key with `gpg --detach-sign --digest-algo sha512`.
+const RSA: PgpSignature = PgpSignature(concat!(
+    "iQFEBAABCgAuFiEEinb+b1GureAuC8MXs7vaIj+JuycFAmrTI/MQHHJzYUBleGFtcGxlLmNvbQAKCRCzu9oiP4m7J+c3CACr",
+    "VE0ouqml1HX4cjjqhO5sWxPZSIwIpDwXkYQufn45MRzxVUniptn3jvZQzPVSmH3Rzn02yfXnQL5MuKtwlHmezj5Kp5IupNiQ",
+    "9FRvOIGulEIwtttMy0tdMEfRuy03iz0zRA1KkwKEVR1CKodZT47aB2meWJKPIoUs+g7pxaUcu2iNHXyUhSAqKEKRHzC5vodk",
+    "bNKWb1TRS0uxqg/ZbLHG2lEKMHngL2E0Z7m2lTGYIhszP8S3i0/w5rXjpFYAj+O3ys1SBlFEquaISHAZ61m1/sxvWkUMQF+q",
+    "A3ZdWDwEH3pGnG4EJTe5N8fCLz2s60ggT2Y8BoDxaLjAbiC/t63B",
+));
+
+#[test]
+fn desc() {
+    let desc = ParsedDesc::parse(GNOME_SHELL).unwrap();
+    let signature = desc.pgp_signature().unwrap().parse().unwrap();
+    assert_eq!(
+        signature,
+        ParsedPgpSignature {
+            version: 4,
+            signature_type: 0x00,
+            public_key_algorithm: 22,
+            hash_algorithm: PgpHashAlgorithm::Sha512,
+            creation_time: Some(1716690900),
+            issuer_key_id: Some(hex!("B8AC08600F108CDF")),
+            issuer_fingerprint: Some(PgpFingerprint::V4(hex!(
+                "83BC8889351B5DEBBB68416EB8AC08600F108CDF"
+            ))),
+        },
+    );
+    assert!(signature.is_issued_by(PgpKey("83BC8889351B5DEBBB68416EB8AC08600F108CDF")));
+    assert!(signature.is_issued_by(PgpKey("83bc8889351b5debbb68416eb8ac08600f108cdf")));
+    assert!(signature.is_issued_by(PgpKey("B8AC08600F108CDF")));
+    assert!(!signature.is_issued_by(PgpKey("B9C0A582C442D58B787660A278F22916FCB4CCC7")));
+    assert!(!signature.is_issued_by(PgpKey("78F22916FCB4CCC7")));
+    assert!(!signature.is_issued_by(PgpKey("not a key")));
+}
+
+#[test]
+fn ed25519() {
+    let signature = ED25519.parse().unwrap();
+    assert_eq!(
+        signature,
+        ParsedPgpSignature {
+            version: 4,
+            signature_type: 0x00,
+            public_key_algorithm: 22,
+            hash_algorithm: PgpHashAlgorithm::Sha256,
+            creation_time: Some(1792222195),
+            issuer_key_id: Some(hex!("78F22916FCB4CCC7")),
+            issuer_fingerprint: Some(PgpFingerprint::V4(hex!(
+                "B9C0A582C442D58B787660A278F22916FCB4CCC7"
+            ))),
+        },
+    );
+    assert_eq!(signature.key_id(), Some(hex!("78F22916FCB4CCC7")));
+}
+
+#[test]
+fn rsa() {
+    let signature = RSA.parse().unwrap();
+    assert_eq!(signature.version, 4);
+    assert_eq!(signature.public_key_algorithm, 1);
+    assert_eq!(signature.hash_algorithm, PgpHashAlgorithm::Sha512);
+    assert_eq!(signature.hash_algorithm.to_string(), "SHA512");
+    assert!(signature.is_issued_by(PgpKey("8A76FE6F51AEADE02E0BC317B3BBDA223F89BB27")));
+}
+
+#[test]
+fn decode() {
+    let bytes = ED25519.decode().unwrap();
+    assert_eq!(ED25519.decoded_len(), Ok(141));
+    assert_eq!(bytes.len(), 141);
+    assert_eq!(&bytes[..2], &[0x88, 0x8B]);
+
+    let mut buffer = [0; 141];
+    assert_eq!(ED25519.decode_to_slice(&mut buffer), Ok(141));
+    assert_eq!(buffer.as_slice(), bytes.as_slice());
+
+    let mut buffer = [0; 140];
+    assert_eq!(
+        ED25519.decode_to_slice(&mut buffer),
+        Err(DecodeBase64Error::OutputTooSmall(141)),
+    );
+}
+
+#[test]
+fn new_packet_format() {
+    let mut bytes = ED25519.decode().unwrap();
+    bytes[0] = 0xC2; // new format, tag 2, followed by the same one-octet length
+    assert_eq!(
+        ParsedPgpSignature::parse_packet(&bytes),
+        Ok(ED25519.parse().unwrap()),
+    );
+
+    let mut bytes = RSA.decode().unwrap();
+    assert_eq!(&bytes[..3], &[0x89, 0x01, 0x44]); // old format, two-octet length of 324
+    bytes.splice(..3, [0xC2, 0xC0, 0x84]); // new format, two-octet length of 324
+    assert_eq!(
+        ParsedPgpSignature::parse_packet(&bytes),
+        Ok(RSA.parse().unwrap()),
+    );
+}
+
+#[test]
+fn version_3() {
+    let mut bytes = vec![0x88, 0x00, 0x03, 0x05, 0x00];
+    bytes.extend(hex!("66529FD4"));
+    bytes.extend(hex!("B8AC08600F108CDF"));
+    bytes.extend([0x01, 0x08, 0x12, 0x34]);
+    bytes.extend([0x00, 0x08, 0xFF]); // signature MPI
+    bytes[1] = (bytes.len() - 2) as u8;
+    assert_eq!(
+        ParsedPgpSignature::parse_packet(&bytes),
+        Ok(ParsedPgpSignature {
+            version: 3,
+            signature_type: 0x00,
+            public_key_algorithm: 1,
+            hash_algorithm: PgpHashAlgorithm::Sha256,
+            creation_time: Some(0x66529FD4),
+            issuer_key_id: Some(hex!("B8AC08600F108CDF")),
+            issuer_fingerprint: None,
+        }),
+    );
+}
+
+#[test]
+fn errors() {
+    let bytes = ED25519.decode().unwrap();
+
+    assert_eq!(
+        ParsedPgpSignature::parse_packet(&bytes[..50]),
+        Err(ParsePgpSignaturePacketError::UnexpectedEnd),
+    );
+    assert_eq!(
+        ParsedPgpSignature::parse_packet(&[]),
+        Err(ParsePgpSignaturePacketError::UnexpectedEnd),
+    );
+    assert_eq!(
+        ParsedPgpSignature::parse_packet(&bytes[2..]),
+        Err(ParsePgpSignaturePacketError::InvalidHeader(0x04)),
+    );
+
+    let mut public_key = bytes.clone();
+    public_key[0] = 0x98; // old format, tag 6
+    assert_eq!(
+        ParsedPgpSignature::parse_packet(&public_key),
+        Err(ParsePgpSignaturePacketError::NotSignature(6)),
+    );
+
+    let mut version_5 = bytes.clone();
+    version_5[2] = 5;
+    assert_eq!(
+        ParsedPgpSignature::parse_packet(&version_5),
+        Err(ParsePgpSignaturePacketError::UnsupportedVersion(5)),
+    );
+
+    let mut partial = bytes.clone();
+    partial.splice(..2, [0xC2, 0xE0]);
+    assert_eq!(
+        ParsedPgpSignature::parse_packet(&partial),
+        Err(ParsePgpSignaturePacketError::PartialBodyLength),
+    );
+
+    assert_eq!(
+        PgpSignature("iIsE!A").parse(),
+        Err(ParsePgpSignatureError::Decode(
+            DecodeBase64Error::InvalidCharacter(4, '!')
+        )),
+    );
+    assert_eq!(
+        PgpSignature("iAQE").parse().unwrap_err().to_string(),
+        "Failed to parse signature packet: Unexpected end of input",
+    );
+}
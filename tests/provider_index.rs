@@ -0,0 +1,101 @@
+#![cfg(feature = "std")]
+
+use arch_pkg_text::{
+    parse::ParsedDesc,
+    resolve::ProviderIndex,
+    value::{Dependency, Name},
+};
+use pipe_trait::Pipe;
+use pretty_assertions::assert_eq;
+
+const BAR: &str = include_str!("fixtures/sync-db/packages/bar-2.0-1/desc");
+const FOO: &str = include_str!("fixtures/sync-db/packages/foo-1.0-1/desc");
+
+const MESA: &str =
+    "%NAME%\nmesa\n\n%VERSION%\n1:24.2.7-1\n\n%PROVIDES%\nlibgl\nlibGL.so=1-64\nopengl-driver\n";
+const NVIDIA_UTILS: &str = "%NAME%\nnvidia-utils\n\n%VERSION%\n565.57.01-1\n\n%PROVIDES%\nlibgl=565.57.01\nopengl-driver\n";
+const LIBGL: &str = "%NAME%\nlibgl\n\n%VERSION%\n1.0-1\n";
+const BASH: &str = "%NAME%\nbash\n\n%VERSION%\n5.2.037-1\n\n%PROVIDES%\nsh\n";
+const NAMELESS: &str = "%VERSION%\n1.0-1\n\n%PROVIDES%\nsh\n";
+
+fn index() -> ProviderIndex<'static, ParsedDesc<'static>> {
+    [BAR, FOO, MESA, NVIDIA_UTILS, LIBGL, BASH, NAMELESS]
+        .map(|text| ParsedDesc::parse(text).unwrap())
+        .pipe(ProviderIndex::new)
+}
+
+fn providers(
+    index: &ProviderIndex<'static, ParsedDesc<'static>>,
+    dependency: &str,
+) -> Vec<(Name<'static>, Option<&'static str>)> {
+    index
+        .providers(Dependency(dependency))
+        .into_iter()
+        .map(|provider| {
+            (
+                provider.name,
+                provider.provision.map(|provision| provision.as_str()),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn basic() {
+    let index = index();
+    assert_eq!(index.len(), 6);
+    assert!(!index.is_empty());
+    assert!(index.get("libgl").is_some());
+    assert!(index.get("sh").is_none());
+    assert_eq!(providers(&index, "bash"), [(Name("bash"), None)]);
+    assert_eq!(providers(&index, "sh"), [(Name("bash"), Some("sh"))]);
+    assert_eq!(providers(&index, "does-not-exist"), []);
+}
+
+#[test]
+fn exact_name_first() {
+    let index = index();
+    assert_eq!(
+        providers(&index, "libgl"),
+        [
+            (Name("libgl"), None),
+            (Name("mesa"), Some("libgl")),
+            (Name("nvidia-utils"), Some("libgl=565.57.01")),
+        ],
+    );
+}
+
+#[test]
+fn versioned() {
+    let index = index();
+    assert_eq!(
+        providers(&index, "libgl>=2"),
+        [(Name("nvidia-utils"), Some("libgl=565.57.01"))],
+    );
+    assert_eq!(providers(&index, "libgl<1.0"), []);
+    assert_eq!(providers(&index, "libgl=1.0"), [(Name("libgl"), None)]);
+    assert_eq!(providers(&index, "bash>=5"), [(Name("bash"), None)]);
+    assert_eq!(providers(&index, "bash>=6"), []);
+    assert_eq!(providers(&index, "sh>=1"), []);
+    assert_eq!(providers(&index, "mesa>=1:24"), [(Name("mesa"), None)]);
+    assert_eq!(providers(&index, "mesa>=25"), [(Name("mesa"), None)]);
+    assert_eq!(providers(&index, "mesa>=2:0"), []);
+}
+
+#[test]
+fn sonames() {
+    let index = index();
+    assert_eq!(
+        providers(&index, "libGL.so=1-64"),
+        [(Name("mesa"), Some("libGL.so=1-64"))],
+    );
+    assert_eq!(
+        providers(&index, "libGL.so"),
+        [(Name("mesa"), Some("libGL.so=1-64"))],
+    );
+    assert_eq!(providers(&index, "libGL.so=1-32"), []);
+    assert_eq!(
+        providers(&index, "libbar.so=2-64"),
+        [(Name("bar"), Some("libbar.so=2-64"))],
+    );
+}
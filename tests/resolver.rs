@@ -0,0 +1,219 @@
+#![cfg(feature = "std")]
+
+use arch_pkg_text::{
+    parse::ParsedDesc,
+    resolve::{ProviderIndex, Resolution, ResolveIssue, ResolveReason, Resolver},
+    value::{Dependency, Name},
+};
+use pipe_trait::Pipe;
+use pretty_assertions::assert_eq;
+
+fn repository(texts: &[&'static str]) -> ProviderIndex<'static, ParsedDesc<'static>> {
+    texts
+        .iter()
+        .map(|text| ParsedDesc::parse(text).unwrap())
+        .pipe(ProviderIndex::new)
+}
+
+const CORE: &[&str] = &[
+    "%NAME%\nbash\n\n%VERSION%\n5.2.037-1\n\n%DEPENDS%\nreadline\nlibreadline.so=8-64\nglibc\n\n%PROVIDES%\nsh\n",
+    "%NAME%\nreadline\n\n%VERSION%\n8.2.013-1\n\n%DEPENDS%\nglibc\nncurses\n\n%PROVIDES%\nlibreadline.so=8-64\n",
+    "%NAME%\nglibc\n\n%VERSION%\n2.40-1\n",
+    "%NAME%\nncurses\n\n%VERSION%\n6.5-3\n\n%DEPENDS%\nglibc\n",
+    "%NAME%\ndash\n\n%VERSION%\n0.5.12-1\n\n%PROVIDES%\nsh\n",
+    "%NAME%\nfoo\n\n%VERSION%\n1.0-1\n\n%DEPENDS%\nglibc>=3\n",
+    "%NAME%\ncycle-a\n\n%VERSION%\n1.0-1\n\n%DEPENDS%\ncycle-b\n",
+    "%NAME%\ncycle-b\n\n%VERSION%\n1.0-1\n\n%DEPENDS%\ncycle-a\nglibc\n",
+];
+
+const EXTRA: &[&str] = &[
+    "%NAME%\nglibc\n\n%VERSION%\n3.0-1\n",
+    "%NAME%\nzsh\n\n%VERSION%\n5.9-5\n\n%DEPENDS%\nsh\n\n%CONFLICTS%\ndash\n",
+    "%NAME%\nmksh\n\n%VERSION%\nR59c-1\n\n%PROVIDES%\nksh\n\n%CONFLICTS%\nbash\n",
+];
+
+fn names<'a, Package>(resolution: &Resolution<'_, 'a, Package>) -> Vec<Name<'a>> {
+    resolution
+        .packages
+        .iter()
+        .map(|package| package.name)
+        .collect()
+}
+
+#[test]
+fn dependencies_come_first() {
+    let core = repository(CORE);
+    let resolution = Resolver::new([&core]).resolve(["bash"]);
+    assert_eq!(resolution.issues, []);
+    assert_eq!(
+        names(&resolution),
+        [
+            Name("glibc"),
+            Name("ncurses"),
+            Name("readline"),
+            Name("bash")
+        ],
+    );
+    let reasons: Vec<_> = resolution
+        .packages
+        .iter()
+        .map(|package| package.reason)
+        .collect();
+    assert_eq!(
+        reasons,
+        [
+            ResolveReason::Dependency {
+                package: Name("bash"),
+                dependency: Dependency("glibc"),
+            },
+            ResolveReason::Dependency {
+                package: Name("readline"),
+                dependency: Dependency("ncurses"),
+            },
+            ResolveReason::Dependency {
+                package: Name("bash"),
+                dependency: Dependency("readline"),
+            },
+            ResolveReason::Target,
+        ],
+    );
+}
+
+#[test]
+fn provided_target() {
+    let core = repository(CORE);
+    let resolution = Resolver::new([&core]).resolve(["sh"]);
+    assert_eq!(resolution.issues, []);
+    assert_eq!(names(&resolution).last(), Some(&Name("bash")));
+}
+
+#[test]
+fn repository_priority() {
+    let core = repository(CORE);
+    let extra = repository(EXTRA);
+
+    let resolution = Resolver::new([&core, &extra]).resolve(["glibc"]);
+    let versions: Vec<_> = resolution
+        .packages
+        .iter()
+        .map(|package| (package.version.unwrap().as_str(), package.repository))
+        .collect();
+    assert_eq!(versions, [("2.40-1", 0)]);
+
+    let resolution = Resolver::new([&extra, &core]).resolve(["glibc"]);
+    let versions: Vec<_> = resolution
+        .packages
+        .iter()
+        .map(|package| (package.version.unwrap().as_str(), package.repository))
+        .collect();
+    assert_eq!(versions, [("3.0-1", 0)]);
+}
+
+#[test]
+fn unresolvable() {
+    let core = repository(CORE);
+    let resolution = Resolver::new([&core]).resolve(["foo", "does-not-exist"]);
+    assert_eq!(
+        resolution.issues,
+        [
+            ResolveIssue::TargetNotFound("does-not-exist".to_string()),
+            ResolveIssue::UnresolvableDependency {
+                package: Name("foo"),
+                dependency: Dependency("glibc>=3"),
+            },
+        ],
+    );
+    assert_eq!(
+        resolution.issues[1].to_string(),
+        "No package satisfies dependency glibc>=3 of foo",
+    );
+    assert!(resolution.into_result().is_err());
+}
+
+#[test]
+fn versioned_dependency_from_lower_priority_repository() {
+    let core = repository(CORE);
+    let extra = repository(EXTRA);
+    let resolution = Resolver::new([&core, &extra]).resolve(["foo"]);
+    assert_eq!(resolution.issues, []);
+    let packages: Vec<_> = resolution
+        .packages
+        .iter()
+        .map(|package| (package.name, package.repository))
+        .collect();
+    assert_eq!(packages, [(Name("glibc"), 1), (Name("foo"), 0)]);
+}
+
+#[test]
+fn unsatisfied_version() {
+    let core = repository(CORE);
+    let extra = repository(EXTRA);
+    let resolution = Resolver::new([&core, &extra]).resolve(["glibc", "foo"]);
+    assert_eq!(
+        resolution.issues,
+        [ResolveIssue::UnsatisfiedVersion {
+            package: Name("foo"),
+            dependency: Dependency("glibc>=3"),
+            selected: Name("glibc"),
+        }],
+    );
+    assert_eq!(
+        resolution.issues[0].to_string(),
+        "Dependency glibc>=3 of foo is not satisfied by the selected glibc",
+    );
+    assert_eq!(names(&resolution), [Name("glibc"), Name("foo")]);
+}
+
+#[test]
+fn conflicts() {
+    let core = repository(CORE);
+    let extra = repository(EXTRA);
+    let resolution = Resolver::new([&core, &extra]).resolve(["dash", "zsh", "mksh", "bash"]);
+    assert_eq!(
+        resolution.issues,
+        [
+            ResolveIssue::Conflict {
+                package: Name("zsh"),
+                other: Name("dash"),
+                conflict: Dependency("dash"),
+            },
+            ResolveIssue::Conflict {
+                package: Name("mksh"),
+                other: Name("bash"),
+                conflict: Dependency("bash"),
+            },
+        ],
+    );
+    assert_eq!(
+        resolution.issues[0].to_string(),
+        "zsh conflicts with dash (dash)"
+    );
+}
+
+#[test]
+fn mutual_conflict() {
+    let local = repository(&[
+        "%NAME%\nfoo\n\n%VERSION%\n1.0-1\n\n%CONFLICTS%\nbar\n",
+        "%NAME%\nbar\n\n%VERSION%\n1.0-1\n\n%CONFLICTS%\nfoo\n",
+    ]);
+    let resolution = Resolver::new([&local]).resolve(["foo", "bar"]);
+    assert_eq!(
+        resolution.issues,
+        [ResolveIssue::Conflict {
+            package: Name("foo"),
+            other: Name("bar"),
+            conflict: Dependency("bar"),
+        }],
+    );
+}
+
+#[test]
+fn cycle() {
+    let core = repository(CORE);
+    let resolution = Resolver::new([&core]).resolve(["cycle-a"]);
+    assert_eq!(resolution.issues, []);
+    assert_eq!(
+        names(&resolution),
+        [Name("glibc"), Name("cycle-b"), Name("cycle-a")],
+    );
+}
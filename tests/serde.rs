@@ -0,0 +1,209 @@
+#![cfg(all(feature = "serde", feature = "std"))]
+
+pub mod _utils;
+pub use _utils::*;
+
+use arch_pkg_text::{
+    desc::Query as _,
+    parse::{OwnedDesc, OwnedSrcinfo, ParsedDesc, ParsedSrcinfo},
+    srcinfo::{Query as _, QueryItem, Section},
+    value::{Architecture, Dependency, Name, Size},
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+const DESC_FIXTURES: &[(&str, &str)] = &[
+    (
+        "gnome-shell.desc",
+        include_str!("fixtures/gnome-shell.desc"),
+    ),
+    ("local-bash.desc", include_str!("fixtures/local-bash.desc")),
+];
+
+const SRCINFO_FIXTURES: &[(&str, &str)] = &[
+    ("complex", COMPLEX),
+    ("simple", SIMPLE),
+    ("has-empty-values", HAS_EMPTY_VALUES),
+    ("multiple-checksum-types", MULTIPLE_CHECKSUM_TYPES),
+];
+
+#[test]
+fn values() {
+    assert_eq!(serde_json::to_value(Name("foo")).unwrap(), json!("foo"));
+    assert_eq!(
+        serde_json::from_str::<Name>(r#""foo""#).unwrap(),
+        Name("foo"),
+    );
+    let size: Size = serde_json::from_str(r#""123""#).unwrap();
+    assert_eq!(size.parse(), Ok(123));
+
+    let desc = ParsedDesc::parse("%DEPENDS%\nglibc\nbash\n").unwrap();
+    assert_eq!(
+        serde_json::to_value(desc.dependencies().unwrap()).unwrap(),
+        json!(["glibc", "bash"]),
+    );
+}
+
+#[test]
+fn query_item() {
+    let item = QueryItem {
+        value: Dependency("glibc"),
+        section: Section::Derivative(Name("foo")),
+        architecture: Some(Architecture("x86_64")),
+    };
+    let value = json!({
+        "value": "glibc",
+        "section": { "Derivative": "foo" },
+        "architecture": "x86_64",
+    });
+    assert_eq!(serde_json::to_value(item).unwrap(), value);
+    let text = value.to_string();
+    assert_eq!(
+        serde_json::from_str::<QueryItem<_, _>>(&text).unwrap(),
+        item
+    );
+
+    let item = QueryItem {
+        value: Name("bar"),
+        section: Section::Base,
+        architecture: (),
+    };
+    let text = serde_json::to_string(&item).unwrap();
+    assert_eq!(
+        text,
+        r#"{"value":"bar","section":"Base","architecture":null}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<QueryItem<_, _>>(&text).unwrap(),
+        item
+    );
+}
+
+#[test]
+fn desc() {
+    let desc = ParsedDesc::parse(concat!(
+        "%NAME%\nfoo\n\n",
+        "%VERSION%\n1.2.3-1\n\n",
+        "%LICENSE%\nMIT\n\n",
+        "%DEPENDS%\nglibc\nbash\n",
+    ))
+    .unwrap();
+    assert_eq!(
+        serde_json::to_value(desc).unwrap(),
+        json!({
+            "NAME": "foo",
+            "VERSION": "1.2.3-1",
+            "LICENSE": ["MIT"],
+            "DEPENDS": ["glibc", "bash"],
+        }),
+    );
+}
+
+#[test]
+fn desc_round_trip() {
+    for (name, text) in DESC_FIXTURES {
+        eprintln!("CASE: {name}");
+        let parsed = ParsedDesc::parse(text).unwrap();
+        let json = serde_json::to_string(&parsed).unwrap();
+        let owned: OwnedDesc = serde_json::from_str(&json).unwrap();
+        assert_eq!(owned.as_parsed(), parsed);
+        assert_eq!(serde_json::to_string(&owned).unwrap(), json);
+    }
+}
+
+#[test]
+fn desc_unknown_fields_are_ignored() {
+    let owned: OwnedDesc =
+        serde_json::from_value(json!({ "NAME": "foo", "UNKNOWN": ["bar"] })).unwrap();
+    assert_eq!(owned.text(), "%NAME%\nfoo\n\n");
+}
+
+#[test]
+fn srcinfo() {
+    let srcinfo = ParsedSrcinfo::parse(concat!(
+        "pkgbase = foo\n",
+        "pkgver = 1.2.3\n",
+        "arch = x86_64\n",
+        "depends = glibc\n",
+        "depends_x86_64 = lib32-glibc\n",
+        "depends = bash\n",
+        "pkgname = foo\n",
+        "pkgname = bar\n",
+        "pkgdesc = Bar\n",
+    ))
+    .try_into_complete()
+    .unwrap();
+    assert_eq!(
+        serde_json::to_value(&srcinfo).unwrap(),
+        json!({
+            "base": {
+                "pkgbase": "foo",
+                "pkgver": "1.2.3",
+                "arch": ["x86_64"],
+                "depends": ["glibc", "bash"],
+                "depends_x86_64": ["lib32-glibc"],
+            },
+            "derivatives": {
+                "foo": {},
+                "bar": { "pkgdesc": "Bar" },
+            },
+        }),
+    );
+}
+
+#[test]
+fn srcinfo_round_trip() {
+    for (name, text) in SRCINFO_FIXTURES {
+        eprintln!("CASE: {name}");
+        let parsed = ParsedSrcinfo::parse(text).try_into_complete().unwrap();
+        let json = serde_json::to_string(&parsed).unwrap();
+        let owned: OwnedSrcinfo = serde_json::from_str(&json).unwrap();
+        assert_eq!(owned.text(), parsed.to_string());
+        assert_eq!(
+            (&owned).derivative_names().collect::<Vec<_>>(),
+            parsed.derivative_names().collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            serde_json::to_value(&owned).unwrap(),
+            serde_json::to_value(&parsed).unwrap(),
+        );
+    }
+}
+
+#[test]
+fn srcinfo_cleared_fields() {
+    let srcinfo = ParsedSrcinfo::parse(concat!(
+        "pkgbase = foo\n",
+        "depends = glibc\n",
+        "pkgname = foo\n",
+        "pkgname = foo-docs\n",
+        "depends = \n",
+    ))
+    .try_into_complete()
+    .unwrap();
+    let value = json!({
+        "base": {
+            "pkgbase": "foo",
+            "depends": ["glibc"],
+        },
+        "derivatives": {
+            "foo": {},
+            "foo-docs": { "depends": [""] },
+        },
+    });
+    assert_eq!(serde_json::to_value(&srcinfo).unwrap(), value);
+    let owned: OwnedSrcinfo = serde_json::from_value(value).unwrap();
+    assert_eq!(owned.text(), srcinfo.to_string());
+}
+
+#[test]
+fn srcinfo_duplicated_unique_field() {
+    let error = serde_json::from_value::<OwnedSrcinfo>(json!({
+        "base": { "pkgbase": "foo", "pkgver": ["1", "2"] },
+    }))
+    .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Failed to insert value to the pkgbase section: Field pkgver is already set",
+    );
+}
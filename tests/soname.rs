@@ -0,0 +1,82 @@
+use arch_pkg_text::{
+    desc::Query,
+    parse::ParsedDesc,
+    value::{Dependency, DependencyName, Name, SonameBits, Version},
+};
+use pretty_assertions::assert_eq;
+
+const GNOME_SHELL: &str = include_str!("fixtures/gnome-shell.desc");
+const BAR: &str = include_str!("fixtures/sync-db/packages/bar-2.0-1/desc");
+
+#[test]
+fn soname_dependencies() {
+    let desc = ParsedDesc::parse(GNOME_SHELL).unwrap();
+    let sonames: Vec<_> = desc
+        .dependencies()
+        .unwrap()
+        .into_iter()
+        .filter_map(|dependency| dependency.soname())
+        .map(|soname| (soname.name(), soname.soversion(), soname.bits()))
+        .collect();
+    assert_eq!(
+        sonames,
+        [(
+            DependencyName("libmutter-14.so"),
+            Some("0"),
+            Some(SonameBits::Bits64),
+        )],
+    );
+}
+
+#[test]
+fn soname_provisions() {
+    let desc = ParsedDesc::parse(BAR).unwrap();
+    let provisions: Vec<_> = desc.provides().unwrap().into_iter().collect();
+    let satisfied = |dependency| {
+        provisions
+            .iter()
+            .any(|provision| Dependency(dependency).satisfied_by_provision(provision))
+    };
+    assert!(satisfied("libbar.so"));
+    assert!(satisfied("libbar.so=2-64"));
+    assert!(!satisfied("libbar.so=1-64"));
+    assert!(!satisfied("libbar.so=2-32"));
+    assert!(!satisfied("libfoo.so=2-64"));
+}
+
+#[test]
+fn not_soname() {
+    let dependencies = [
+        "bar",
+        "bar=2.0-1",
+        "libbar",
+        "libbar.so.2",
+        "libbar.so>=2-64",
+        "libbar.so=2",
+        "libbar.so=2-x86_64",
+        "libbar.so=-64",
+        ".so=2-64",
+    ];
+    for dependency in dependencies {
+        eprintln!("CASE: {dependency}");
+        assert!(Dependency(dependency).soname().is_none());
+    }
+}
+
+#[test]
+fn soname_against_package_provision() {
+    assert!(!Dependency("libbar.so=2-64").satisfied_by_provision(&Dependency("libbar.so=2")));
+    assert!(!Dependency("libbar.so").satisfied_by_provision(&Dependency("bar=2.0-1")));
+    assert!(!Dependency("bar").satisfied_by_provision(&Dependency("libbar.so=2-64")));
+}
+
+#[test]
+fn unversioned_soname_by_name() {
+    assert!(Dependency("libbar.so").satisfied_by_provision(&Dependency("libbar.so=2")));
+    assert!(Dependency("libbar.so").satisfied_by_provision(&Dependency("libbar.so")));
+    assert!(!Dependency("libbar.so").satisfied_by_provision(&Dependency("libfoo.so=2-64")));
+    assert!(
+        Dependency("libbar.so").satisfied_by_package(Name("libbar.so"), Some(Version("2.0-1")))
+    );
+    assert!(!Dependency("libbar.so=2-64").satisfied_by_package(Name("libbar.so"), None));
+}
@@ -0,0 +1,251 @@
+use arch_pkg_text::value::{FileName, Source, SourceFragment, SourceVcs};
+use pretty_assertions::assert_eq;
+
+/// Expected interpretation of a source entry.
+#[derive(Debug, PartialEq, Eq)]
+struct Expected<'a> {
+    file_name: &'a str,
+    protocol: &'a str,
+    url: &'a str,
+    vcs: Option<SourceVcs>,
+    fragment: Option<SourceFragment<'a>>,
+    signed: bool,
+}
+
+const CASES: &[(&str, Expected)] = &[
+    (
+        "https://example.com/foo-1.0.tar.gz",
+        Expected {
+            file_name: "foo-1.0.tar.gz",
+            protocol: "https",
+            url: "https://example.com/foo-1.0.tar.gz",
+            vcs: None,
+            fragment: None,
+            signed: false,
+        },
+    ),
+    (
+        "foo-1.0.tar.gz::https://example.com/archive/v1.0.tar.gz",
+        Expected {
+            file_name: "foo-1.0.tar.gz",
+            protocol: "https",
+            url: "https://example.com/archive/v1.0.tar.gz",
+            vcs: None,
+            fragment: None,
+            signed: false,
+        },
+    ),
+    (
+        "https://example.com/download.php?file=foo#anchor",
+        Expected {
+            file_name: "download.php?file=foo#anchor",
+            protocol: "https",
+            url: "https://example.com/download.php?file=foo#anchor",
+            vcs: None,
+            fragment: None,
+            signed: false,
+        },
+    ),
+    (
+        "ftp://example.com/pub/foo.tar.xz",
+        Expected {
+            file_name: "foo.tar.xz",
+            protocol: "ftp",
+            url: "ftp://example.com/pub/foo.tar.xz",
+            vcs: None,
+            fragment: None,
+            signed: false,
+        },
+    ),
+    (
+        "foo.install",
+        Expected {
+            file_name: "foo.install",
+            protocol: "local",
+            url: "foo.install",
+            vcs: None,
+            fragment: None,
+            signed: false,
+        },
+    ),
+    (
+        "git+https://github.com/foo/bar.git",
+        Expected {
+            file_name: "bar",
+            protocol: "git",
+            url: "https://github.com/foo/bar.git",
+            vcs: Some(SourceVcs::Git),
+            fragment: None,
+            signed: false,
+        },
+    ),
+    (
+        "git+https://github.com/foo/bar.git#tag=v1.2.3?signed",
+        Expected {
+            file_name: "bar",
+            protocol: "git",
+            url: "https://github.com/foo/bar.git",
+            vcs: Some(SourceVcs::Git),
+            fragment: Some(SourceFragment::Tag("v1.2.3")),
+            signed: true,
+        },
+    ),
+    (
+        "git+https://github.com/foo/bar.git?signed#tag=v1.2.3",
+        Expected {
+            file_name: "bar",
+            protocol: "git",
+            url: "https://github.com/foo/bar.git",
+            vcs: Some(SourceVcs::Git),
+            fragment: Some(SourceFragment::Tag("v1.2.3")),
+            signed: true,
+        },
+    ),
+    (
+        "baz::git+https://github.com/foo/bar#commit=0123456789abcdef",
+        Expected {
+            file_name: "baz",
+            protocol: "git",
+            url: "https://github.com/foo/bar",
+            vcs: Some(SourceVcs::Git),
+            fragment: Some(SourceFragment::Commit("0123456789abcdef")),
+            signed: false,
+        },
+    ),
+    (
+        "baz.git::git+https://github.com/foo/bar#commit=0123456789abcdef",
+        Expected {
+            file_name: "baz",
+            protocol: "git",
+            url: "https://github.com/foo/bar",
+            vcs: Some(SourceVcs::Git),
+            fragment: Some(SourceFragment::Commit("0123456789abcdef")),
+            signed: false,
+        },
+    ),
+    (
+        "git://git.example.com/foo.git#branch=main",
+        Expected {
+            file_name: "foo",
+            protocol: "git",
+            url: "git://git.example.com/foo.git",
+            vcs: Some(SourceVcs::Git),
+            fragment: Some(SourceFragment::Branch("main")),
+            signed: false,
+        },
+    ),
+    (
+        "git+https://github.com/foo/foo.github.io.git",
+        Expected {
+            file_name: "foo",
+            protocol: "git",
+            url: "https://github.com/foo/foo.github.io.git",
+            vcs: Some(SourceVcs::Git),
+            fragment: None,
+            signed: false,
+        },
+    ),
+    (
+        "hg+https://hg.example.com/foo#revision=123",
+        Expected {
+            file_name: "foo",
+            protocol: "hg",
+            url: "https://hg.example.com/foo",
+            vcs: Some(SourceVcs::Mercurial),
+            fragment: Some(SourceFragment::Revision("123")),
+            signed: false,
+        },
+    ),
+    (
+        "svn+https://svn.example.com/foo/trunk/",
+        Expected {
+            file_name: "trunk",
+            protocol: "svn",
+            url: "https://svn.example.com/foo/trunk/",
+            vcs: Some(SourceVcs::Subversion),
+            fragment: None,
+            signed: false,
+        },
+    ),
+    (
+        "fossil+https://fossil.example.com/foo#bookmark=release",
+        Expected {
+            file_name: "foo.fossil",
+            protocol: "fossil",
+            url: "https://fossil.example.com/foo",
+            vcs: Some(SourceVcs::Fossil),
+            fragment: Some(SourceFragment::Bookmark("release")),
+            signed: false,
+        },
+    ),
+    (
+        "bar::fossil+https://fossil.example.com/foo",
+        Expected {
+            file_name: "bar.fossil",
+            protocol: "fossil",
+            url: "https://fossil.example.com/foo",
+            vcs: Some(SourceVcs::Fossil),
+            fragment: None,
+            signed: false,
+        },
+    ),
+    (
+        "bzr+lp:foo",
+        Expected {
+            file_name: "foo",
+            protocol: "bzr",
+            url: "lp:foo",
+            vcs: Some(SourceVcs::Bazaar),
+            fragment: None,
+            signed: false,
+        },
+    ),
+];
+
+#[test]
+fn parse() {
+    for (source, expected) in CASES {
+        eprintln!("CASE: {source:?}");
+        let parsed = Source(source).parse();
+        let file_name = parsed.file_name().to_string();
+        let received = Expected {
+            file_name: &file_name,
+            protocol: parsed.protocol(),
+            url: parsed.url(),
+            vcs: parsed.vcs(),
+            fragment: parsed.fragment(),
+            signed: parsed.is_signed(),
+        };
+        assert_eq!(&received, expected);
+    }
+}
+
+#[test]
+fn rename() {
+    let source = Source("foo.tar.gz::https://example.com/foo.tar.gz").parse();
+    assert_eq!(source.rename(), Some(FileName("foo.tar.gz")));
+    let source = Source("https://example.com/foo.tar.gz").parse();
+    assert_eq!(source.rename(), None);
+}
+
+#[test]
+fn fragment_components() {
+    let fragment = Source("git+https://example.com/foo.git#tag=v1.0")
+        .parse()
+        .fragment()
+        .unwrap();
+    assert_eq!((fragment.kind(), fragment.value()), ("tag", "v1.0"));
+
+    let fragment = Source("git+https://example.com/foo.git#unknown=value")
+        .parse()
+        .fragment()
+        .unwrap();
+    assert_eq!(
+        fragment,
+        SourceFragment::Other {
+            kind: "unknown",
+            value: "value",
+        },
+    );
+    assert_eq!((fragment.kind(), fragment.value()), ("unknown", "value"));
+}
@@ -0,0 +1,142 @@
+#![cfg(feature = "std")]
+
+pub mod _utils;
+pub use _utils::*;
+
+use arch_pkg_text::{
+    srcinfo::{
+        ChecksumArray, ChecksumCountMismatch, ChecksumType, ChecksumValue, ForgetfulQuerier,
+        Section, SourceChecksumPairs,
+    },
+    value::{Architecture, Name, Source},
+};
+use hex_lit::hex;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn complex() {
+    let querier = ForgetfulQuerier::new(COMPLEX);
+    let entries = SourceChecksumPairs::new(&querier).into_result().unwrap();
+    let entries: Vec<_> = entries
+        .iter()
+        .map(|entry| {
+            let [checksum] = entry.checksums.as_slice() else {
+                panic!("expecting exactly 1 checksum: {entry:?}");
+            };
+            assert!(matches!(checksum, ChecksumValue::Sha1(_)));
+            (entry.source, entry.section, entry.architecture)
+        })
+        .collect();
+    dbg!(&entries);
+    assert_eq!(
+        entries,
+        [
+            (
+                Source("https://src.apps.example.com/complex.tgz"),
+                Section::Base,
+                None,
+            ),
+            (
+                Source("build-x86_64.bash"),
+                Section::Base,
+                Some(Architecture("x86_64")),
+            ),
+            (
+                Source("build-aarch64.bash"),
+                Section::Base,
+                Some(Architecture("aarch64")),
+            ),
+            (
+                Source("build-foo.bash"),
+                Section::Derivative(Name("foo-bin")),
+                None,
+            ),
+            (
+                Source("build-bar.bash"),
+                Section::Derivative(Name("bar-bin")),
+                None,
+            ),
+        ],
+    );
+}
+
+#[test]
+fn multiple_checksum_types() {
+    let querier = ForgetfulQuerier::new(MULTIPLE_CHECKSUM_TYPES);
+    let pairs = SourceChecksumPairs::new(&querier);
+    assert!(pairs.mismatches.is_empty());
+    let entries: Vec<_> = pairs
+        .entries
+        .iter()
+        .map(|entry| {
+            let checksums: Vec<_> = entry
+                .checksums
+                .iter()
+                .map(|checksum| checksum.u8_array())
+                .collect();
+            (entry.source, checksums)
+        })
+        .collect();
+    dbg!(&entries);
+    assert_eq!(
+        entries,
+        [
+            (
+                Source("foo.txt"),
+                vec![
+                    Some(ChecksumArray::Md5(hex!("55e46a9fde34babc87ff29cefec7fa87"))),
+                    Some(ChecksumArray::Sha1(hex!(
+                        "ee15d4c86f91b296327ac552c5b214e1e2102a38"
+                    ))),
+                ],
+            ),
+            (
+                Source("bar.txt"),
+                vec![
+                    Some(ChecksumArray::Md5(hex!("3daf117a8bc1700d997ca044bbb386cc"))),
+                    Some(ChecksumArray::Sha1(hex!(
+                        "e33a9949d6206a799a25daf21056761119c8227e"
+                    ))),
+                ],
+            ),
+        ],
+    );
+}
+
+#[test]
+fn mismatches() {
+    let querier = ForgetfulQuerier::new(concat!(
+        "pkgbase = foo\n",
+        "\tsource = foo.txt\n",
+        "\tsource = bar.txt\n",
+        "\tmd5sums = SKIP\n",
+        "\tmd5sums = SKIP\n",
+        "\tsha256sums = SKIP\n",
+        "\tsource_aarch64 = baz.txt\n",
+        "\tmd5sums_aarch64 = SKIP\n",
+        "pkgname = foo\n",
+        "pkgname = bar\n",
+        "\tsource = bar.patch\n",
+    ));
+    let mismatches = SourceChecksumPairs::new(&querier)
+        .into_result()
+        .unwrap_err();
+    let messages: Vec<_> = mismatches.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        messages,
+        [
+            "sha256sums of pkgbase has 1 entry but source has 2",
+            "sha256sums_aarch64 of pkgbase has 0 entries but source_aarch64 has 1",
+        ],
+    );
+    assert_eq!(
+        mismatches[1],
+        ChecksumCountMismatch {
+            section: Section::Base,
+            architecture: Some(Architecture("aarch64")),
+            checksum_type: ChecksumType::Sha256,
+            sources: 1,
+            checksums: 0,
+        },
+    );
+}
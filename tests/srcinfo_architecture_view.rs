@@ -0,0 +1,107 @@
+#![cfg(feature = "std")]
+
+pub mod _utils;
+pub use _utils::*;
+
+use arch_pkg_text::{
+    parse::ParsedSrcinfo,
+    srcinfo::{ArchitectureView, ChecksumArray, UnsupportedArchitectureError},
+    value::{Architecture, Dependency, Source},
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn dependencies() {
+    let srcinfo = ParsedSrcinfo::try_from(COMPLEX).unwrap();
+    let dependencies = |name: &str, architecture: &'static str| -> Vec<_> {
+        let package = srcinfo.package(name).unwrap();
+        ArchitectureView::new(package, Architecture(architecture))
+            .unwrap()
+            .dependencies()
+            .map(|item| item.value)
+            .collect()
+    };
+
+    eprintln!("CASE: foo-bin, i686");
+    assert_eq!(
+        dependencies("foo-bin", "i686"),
+        [
+            Dependency("extra-depend-for-foo"),
+            Dependency("i686-compatibility-for-foo"),
+        ],
+    );
+
+    eprintln!("CASE: bar-bin, x86_64");
+    assert_eq!(
+        dependencies("bar-bin", "x86_64"),
+        [
+            Dependency("extra-depend-for-bar"),
+            Dependency("x86_64-compatibility-for-bar"),
+        ],
+    );
+
+    eprintln!("CASE: bar-bin, aarch64");
+    assert_eq!(
+        dependencies("bar-bin", "aarch64"),
+        [
+            Dependency("extra-depend-for-bar"),
+            Dependency("aarch64-compatibility"),
+        ],
+    );
+}
+
+#[test]
+fn sources_and_checksums() {
+    let srcinfo = ParsedSrcinfo::try_from(COMPLEX).unwrap();
+    let package = srcinfo.package("bar-bin").unwrap();
+    let view = ArchitectureView::new(package, Architecture("aarch64")).unwrap();
+
+    let sources: Vec<_> = view.source().map(|item| item.value).collect();
+    assert_eq!(
+        sources,
+        [Source("build-bar.bash"), Source("build-aarch64.bash")],
+    );
+
+    let checksums: Vec<_> = view
+        .checksums()
+        .map(|item| item.value.u8_array().unwrap())
+        .collect();
+    assert_eq!(checksums, [ChecksumArray::Skip, ChecksumArray::Skip]);
+
+    let package = srcinfo.package("foo-bin").unwrap();
+    let view = ArchitectureView::new(package, Architecture("i686")).unwrap();
+    let sources: Vec<_> = view.source().map(|item| item.value).collect();
+    assert_eq!(sources, [Source("build-foo.bash")]);
+
+    let srcinfo = ParsedSrcinfo::try_from(SIMPLE).unwrap();
+    let package = srcinfo.packages().next().unwrap();
+    let view = ArchitectureView::new(package, Architecture("riscv64")).unwrap();
+    let checksums: Vec<_> = view
+        .sha1_checksums()
+        .map(|item| item.value.as_str())
+        .collect();
+    assert_eq!(
+        checksums,
+        ["4808c01d2da9ba8a1f0da603d20d515e3e7a67e6", "SKIP", "SKIP"],
+    );
+}
+
+#[test]
+fn unsupported_architecture() {
+    let srcinfo = ParsedSrcinfo::try_from(COMPLEX).unwrap();
+
+    eprintln!("CASE: foo-bin overrides arch");
+    let package = srcinfo.package("foo-bin").unwrap();
+    let error = ArchitectureView::new(package, Architecture("x86_64")).unwrap_err();
+    assert_eq!(
+        error,
+        UnsupportedArchitectureError {
+            architecture: Architecture("x86_64"),
+        },
+    );
+
+    eprintln!("CASE: bar-bin inherits arch");
+    let package = srcinfo.package("bar-bin").unwrap();
+    let error = ArchitectureView::new(package, Architecture("i686")).unwrap_err();
+    assert_eq!(error.to_string(), "Architecture i686 is not supported");
+}
@@ -0,0 +1,139 @@
+#![cfg(feature = "std")]
+
+pub mod _utils;
+pub use _utils::*;
+
+use arch_pkg_text::{
+    parse::ParsedSrcinfo,
+    srcinfo::{
+        ChecksumArray, ChecksumType, Checksums, ChecksumsMut, FieldName, ForgetfulQuerier,
+        MemoQuerier, Query, QueryItem, Section,
+    },
+    value::{Architecture, Name, SkipOrArray, SkipOrCrc32},
+};
+use pretty_assertions::assert_eq;
+
+const SRCINFO: &str = concat!(
+    "pkgbase = foo\n",
+    "\tpkgver = 1.0.0\n",
+    "\tpkgrel = 1\n",
+    "\tarch = x86_64\n",
+    "\tarch = aarch64\n",
+    "\tsource = foo.tar.gz\n",
+    "\tsource_x86_64 = foo-x86_64.patch\n",
+    "\tcksums = 3733384285\n",
+    "\tcksums_x86_64 = SKIP\n",
+    "\n",
+    "pkgname = foo\n",
+    "\n",
+    "pkgname = bar\n",
+    "\tsource = bar.patch\n",
+    "\tcksums = 4294967295\n",
+    "\n",
+);
+
+fn expected_checksums() -> Vec<(
+    Option<ChecksumArray>,
+    Section<'static>,
+    Option<Architecture<'static>>,
+)> {
+    vec![
+        (
+            Some(ChecksumArray::Crc32(3733384285u32.to_be_bytes())),
+            Section::Base,
+            None,
+        ),
+        (
+            Some(ChecksumArray::Skip),
+            Section::Base,
+            Some(Architecture("x86_64")),
+        ),
+        (
+            Some(ChecksumArray::Crc32(u32::MAX.to_be_bytes())),
+            Section::Derivative(Name("bar")),
+            None,
+        ),
+    ]
+}
+
+#[test]
+fn field_name() {
+    assert_eq!("cksums".parse(), Ok(FieldName::Crc32Checksums));
+    assert_eq!(FieldName::Crc32Checksums.to_string(), "cksums");
+    assert_eq!(
+        ChecksumType::Crc32.into_field_name(),
+        FieldName::Crc32Checksums,
+    );
+}
+
+#[test]
+fn value() {
+    assert_eq!(SkipOrCrc32("3733384285").u32(), Some(Some(3733384285)));
+    assert_eq!(SkipOrCrc32("SKIP").u32(), Some(None));
+    assert_eq!(SkipOrCrc32("").u32(), None);
+    assert_eq!(SkipOrCrc32("-1").u32(), None);
+    assert_eq!(SkipOrCrc32("4294967296").u32(), None);
+    assert_eq!(
+        SkipOrCrc32("3733384285").u8_array(),
+        Some(SkipOrArray::Array([0xDE, 0x86, 0xEC, 0x5D])),
+    );
+    assert_eq!(SkipOrCrc32("SKIP").u8_array(), Some(SkipOrArray::Skip));
+}
+
+#[test]
+fn parsed_srcinfo() {
+    let (srcinfo, error) = ParsedSrcinfo::parse(SRCINFO).into_partial();
+    assert!(error.is_none(), "{error:?}");
+
+    let cksums: Vec<_> = srcinfo
+        .base
+        .crc32_checksums()
+        .iter()
+        .map(|(value, architecture)| (value.u32(), *architecture))
+        .collect();
+    assert_eq!(
+        cksums,
+        [
+            (Some(Some(3733384285)), None),
+            (Some(None), Some(Architecture("x86_64"))),
+        ],
+    );
+
+    let checksums: Vec<_> = srcinfo
+        .checksums()
+        .map(QueryItem::into_tuple3)
+        .map(|(value, section, architecture)| (value.u8_array(), section, architecture))
+        .collect();
+    assert_eq!(checksums, expected_checksums());
+}
+
+#[test]
+fn forgetful_querier() {
+    let querier = ForgetfulQuerier::new(SRCINFO);
+    let checksums: Vec<_> = querier
+        .checksums()
+        .map(QueryItem::into_tuple3)
+        .map(|(value, section, architecture)| (value.u8_array(), section, architecture))
+        .collect();
+    assert_eq!(checksums, expected_checksums());
+
+    let cksums: Vec<_> = querier
+        .crc32_checksums()
+        .map(|item| item.value.u32())
+        .collect();
+    assert_eq!(
+        cksums,
+        [Some(Some(3733384285)), Some(None), Some(Some(u32::MAX))]
+    );
+}
+
+#[test]
+fn memo_querier() {
+    let mut querier = MemoQuerier::new(SRCINFO);
+    let checksums: Vec<_> = querier
+        .checksums_mut()
+        .map(QueryItem::into_tuple3)
+        .map(|(value, section, architecture)| (value.u8_array(), section, architecture))
+        .collect();
+    assert_eq!(checksums, expected_checksums());
+}
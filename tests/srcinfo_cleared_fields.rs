@@ -0,0 +1,174 @@
+#![cfg(feature = "std")]
+
+pub mod _utils;
+pub use _utils::*;
+
+use arch_pkg_text::{
+    parse::{OwnedSrcinfo, ParsedSrcinfo},
+    srcinfo::{
+        ClearedFields, ClearedFieldsMut, FieldName, ForgetfulQuerier, MemoQuerier, Query,
+        QueryClearedItem, Section,
+    },
+    value::{Architecture, Dependency, Name},
+};
+use pretty_assertions::assert_eq;
+
+const SRCINFO: &str = concat!(
+    "pkgbase = foo\n",
+    "\tpkgver = 1.0.0\n",
+    "\tpkgrel = 1\n",
+    "\tlicense = \n",
+    "\tdepends = glibc\n",
+    "\tdepends_x86_64 = lib32-glibc\n",
+    "\toptdepends = bash: scripts\n",
+    "\n",
+    "pkgname = foo\n",
+    "\n",
+    "pkgname = foo-docs\n",
+    "\tarch = any\n",
+    "\tdepends = \n",
+    "\toptdepends = \n",
+    "\tpkgver = \n",
+    "\n",
+    "pkgname = foo-devel\n",
+    "\tdepends_x86_64 = \n",
+    "\n",
+);
+
+fn expected_cleared_fields() -> Vec<QueryClearedItem<'static>> {
+    [
+        (FieldName::Dependencies, Name("foo-docs"), None),
+        (FieldName::OptionalDependencies, Name("foo-docs"), None),
+        (
+            FieldName::Dependencies,
+            Name("foo-devel"),
+            Some(Architecture("x86_64")),
+        ),
+    ]
+    .map(|(field_name, name, architecture)| {
+        QueryClearedItem::from_tuple3((field_name, Section::Derivative(name), architecture))
+    })
+    .into()
+}
+
+#[test]
+fn parsed_srcinfo() {
+    let srcinfo = ParsedSrcinfo::try_from(SRCINFO).unwrap();
+    assert_eq!(
+        srcinfo.cleared_fields().collect::<Vec<_>>(),
+        expected_cleared_fields(),
+    );
+
+    assert!(
+        srcinfo.derivatives[&Name("foo")]
+            .cleared_fields()
+            .is_empty()
+    );
+    let docs = &srcinfo.derivatives[&Name("foo-docs")];
+    assert_eq!(
+        docs.cleared_fields(),
+        [
+            (FieldName::Dependencies, None),
+            (FieldName::OptionalDependencies, None),
+        ],
+    );
+    assert!(docs.is_cleared(FieldName::Dependencies, None));
+    assert!(!docs.is_cleared(FieldName::Dependencies, Some(Architecture("x86_64"))));
+    assert!(!docs.is_cleared(FieldName::Version, None));
+    assert!(docs.dependencies().is_empty());
+
+    // empty assignments in the pkgbase section have nothing to clear
+    assert!(srcinfo.base.license().is_empty());
+}
+
+#[test]
+fn queriers() {
+    let expected = expected_cleared_fields();
+
+    eprintln!("CASE: OwnedSrcinfo");
+    let owned = ParsedSrcinfo::try_from(SRCINFO)
+        .map(OwnedSrcinfo::from)
+        .unwrap();
+    assert_eq!((&owned).cleared_fields().collect::<Vec<_>>(), expected);
+    let dependencies: Vec<_> = (&owned).dependencies().map(|item| item.value).collect();
+    assert_eq!(
+        dependencies,
+        [Dependency("glibc"), Dependency("lib32-glibc")],
+    );
+
+    eprintln!("CASE: ForgetfulQuerier");
+    let querier = ForgetfulQuerier::new(SRCINFO);
+    assert_eq!(querier.cleared_fields().collect::<Vec<_>>(), expected);
+
+    eprintln!("CASE: MemoQuerier");
+    let mut querier = MemoQuerier::new(SRCINFO);
+    assert_eq!(querier.cleared_fields_mut().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn package_view() {
+    let srcinfo = ParsedSrcinfo::try_from(SRCINFO).unwrap();
+    let dependencies = |name: &str| -> Vec<_> {
+        srcinfo
+            .package(name)
+            .unwrap()
+            .dependencies()
+            .map(|item| (item.value, item.architecture))
+            .collect()
+    };
+
+    assert_eq!(
+        dependencies("foo"),
+        [
+            (Dependency("glibc"), None),
+            (Dependency("lib32-glibc"), Some(Architecture("x86_64"))),
+        ],
+    );
+    assert_eq!(
+        dependencies("foo-docs"),
+        [(Dependency("lib32-glibc"), Some(Architecture("x86_64")))],
+    );
+    assert_eq!(dependencies("foo-devel"), [(Dependency("glibc"), None)]);
+
+    let docs = srcinfo.package("foo-docs").unwrap();
+    assert_eq!(docs.opt_dependencies().count(), 0);
+    assert_eq!(docs.version().unwrap().as_str(), "1.0.0");
+    assert_eq!(
+        docs.cleared_fields().collect::<Vec<_>>(),
+        expected_cleared_fields()[..2],
+    );
+}
+
+#[test]
+fn write_and_reparse() {
+    let srcinfo = ParsedSrcinfo::try_from(SRCINFO).unwrap();
+    let text = srcinfo.to_string();
+    assert!(text.contains("pkgname = foo-docs\n\tarch = any\n\tdepends = \n\toptdepends = \n\n"));
+    assert!(text.contains("pkgname = foo-devel\n\tdepends_x86_64 = \n\n"));
+    let reparsed = ParsedSrcinfo::try_from(text.as_str()).unwrap();
+    assert_eq!(
+        reparsed.cleared_fields().collect::<Vec<_>>(),
+        expected_cleared_fields(),
+    );
+    assert_eq!(reparsed.to_string(), text);
+}
+
+#[test]
+fn has_empty_values() {
+    let srcinfo = ParsedSrcinfo::try_from(HAS_EMPTY_VALUES).unwrap();
+    assert_eq!(srcinfo.cleared_fields().count(), 0);
+    let names: Vec<_> = srcinfo.derivative_names().collect();
+    assert_eq!(names, [Name("parallel-disk-usage")]);
+    assert_eq!(
+        ForgetfulQuerier::new(HAS_EMPTY_VALUES)
+            .cleared_fields()
+            .count(),
+        0
+    );
+    assert_eq!(
+        MemoQuerier::new(HAS_EMPTY_VALUES)
+            .cleared_fields_mut()
+            .count(),
+        0
+    );
+}
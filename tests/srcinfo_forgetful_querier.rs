@@ -4,8 +4,8 @@ pub use _utils::*;
 use arch_pkg_text::{
     srcinfo::{ChecksumArray, Checksums, ForgetfulQuerier, Query, QueryItem, Section},
     value::{
-        Architecture, Base, Dependency, Description, License, Name, SkipOrArray, Source,
-        UpstreamVersion,
+        Architecture, Base, Dependency, Description, License, Name, ParsedRelease, SkipOrArray,
+        Source, UpstreamVersion,
     },
 };
 use hex_lit::hex;
@@ -19,7 +19,10 @@ fn assert_complex(querier: &ForgetfulQuerier) {
         querier.version().as_ref().map(UpstreamVersion::as_str),
         Some("12.34.56.r789"),
     );
-    assert_eq!(querier.release().unwrap().parse().ok(), Some(2));
+    assert_eq!(
+        querier.release().unwrap().parse(),
+        Ok(ParsedRelease::new(2, None))
+    );
     assert_eq!(querier.epoch().unwrap().parse().ok(), Some(3));
     assert_eq!(
         querier
@@ -171,7 +174,10 @@ fn assert_simple(querier: &ForgetfulQuerier) {
         querier.version().as_ref().map(UpstreamVersion::as_str),
         Some("12.34.56.r789"),
     );
-    assert_eq!(querier.release().unwrap().parse().ok(), Some(1));
+    assert_eq!(
+        querier.release().unwrap().parse(),
+        Ok(ParsedRelease::new(1, None))
+    );
     assert!(querier.epoch().is_none());
     assert_eq!(
         querier
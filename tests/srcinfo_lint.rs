@@ -0,0 +1,347 @@
+#![cfg(feature = "std")]
+
+pub mod _utils;
+pub use _utils::*;
+
+use arch_pkg_text::{
+    lint::{Diagnostic, LintCode, LintIssue, Severity},
+    parse::ParsedSrcinfo,
+    srcinfo::{FieldName, Section},
+    value::{Architecture, Name},
+};
+use pretty_assertions::assert_eq;
+
+fn lint(text: &str) -> Vec<Diagnostic<'_>> {
+    ParsedSrcinfo::parse(text)
+        .try_into_complete()
+        .unwrap()
+        .lint()
+}
+
+fn summarize<'a>(diagnostics: &[Diagnostic<'a>]) -> Vec<(Severity, LintCode, Section<'a>)> {
+    diagnostics
+        .iter()
+        .map(|diagnostic| (diagnostic.severity, diagnostic.code, diagnostic.section))
+        .collect()
+}
+
+fn messages(diagnostics: &[Diagnostic<'_>]) -> Vec<String> {
+    diagnostics.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn clean() {
+    let diagnostics = lint(concat!(
+        "pkgbase = foo\n",
+        "\tpkgver = 1.2.3\n",
+        "\tpkgrel = 1.1\n",
+        "\tarch = x86_64\n",
+        "\tarch = aarch64\n",
+        "\tsource = foo-1.2.3.tar.gz::https://example.com/foo/v1.2.3.tar.gz\n",
+        "\tsource = git+https://example.com/foo-assets.git#tag=v1.2.3\n",
+        "\tsource_aarch64 = arm.patch\n",
+        "\tnoextract = foo-assets\n",
+        "\tsha256sums = SKIP\n",
+        "\tsha256sums = SKIP\n",
+        "\tsha256sums_aarch64 = SKIP\n",
+        "\n",
+        "pkgname = foo\n",
+        "\tbackup = etc/foo.conf\n",
+        "\n",
+        "pkgname = foo-docs\n",
+        "\tarch = any\n",
+        "\n",
+    ));
+    dbg!(&diagnostics);
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn fixtures() {
+    eprintln!("CASE: simple");
+    assert!(lint(SIMPLE).is_empty());
+
+    eprintln!("CASE: complex");
+    assert_eq!(
+        messages(&lint(COMPLEX)),
+        [
+            "warning[unlisted-architecture] pkgname foo-bin: depends_x86_64 is defined but x86_64 is not listed in arch",
+        ],
+    );
+
+    eprintln!("CASE: multiple checksum types");
+    assert_eq!(
+        summarize(&lint(MULTIPLE_CHECKSUM_TYPES)),
+        [(
+            Severity::Error,
+            LintCode::MissingArchitecture,
+            Section::Base,
+        )],
+    );
+}
+
+#[test]
+fn missing_fields() {
+    let diagnostics = lint(concat!("\tarch = any\n", "pkgname = foo\n"));
+    dbg!(&diagnostics);
+    let fields: Vec<_> = diagnostics
+        .iter()
+        .map(|diagnostic| match diagnostic.issue {
+            LintIssue::MissingField(field_name) => field_name,
+            _ => panic!("unexpected diagnostic: {diagnostic}"),
+        })
+        .collect();
+    assert_eq!(
+        fields,
+        [FieldName::Base, FieldName::Version, FieldName::Release],
+    );
+}
+
+#[test]
+fn invalid_name_version_release() {
+    let diagnostics = lint(concat!(
+        "pkgbase = -foo\n",
+        "\tpkgver = 1.0-1\n",
+        "\tpkgrel = one\n",
+        "\tarch = any\n",
+        "pkgname = Foo\n",
+        "pkgname = foo\n",
+    ));
+    assert_eq!(
+        summarize(&diagnostics),
+        [
+            (Severity::Error, LintCode::InvalidName, Section::Base),
+            (
+                Severity::Error,
+                LintCode::InvalidName,
+                Section::Derivative(Name("Foo")),
+            ),
+            (Severity::Error, LintCode::InvalidVersion, Section::Base),
+            (Severity::Error, LintCode::InvalidRelease, Section::Base),
+        ],
+    );
+    assert_eq!(
+        messages(&diagnostics),
+        [
+            r#"error[invalid-name] pkgbase: "-foo" is not a valid package name"#,
+            r#"error[invalid-name] pkgname Foo: "Foo" is not a valid package name"#,
+            r#"error[invalid-version] pkgbase: Invalid pkgver: UpstreamVersion("1.0-1") is not a valid version because '-' is not a valid character"#,
+            r#"error[invalid-release] pkgbase: Invalid pkgrel Release("one"): Invalid major number: invalid digit found in string"#,
+        ],
+    );
+}
+
+#[test]
+fn missing_architecture() {
+    let diagnostics = lint(concat!(
+        "pkgbase = foo\n",
+        "\tpkgver = 1.0\n",
+        "\tpkgrel = 1\n",
+        "pkgname = foo\n",
+        "pkgname = foo-x86\n",
+        "\tarch = x86_64\n",
+        "pkgname = foo-none\n",
+        "\tarch =\n",
+    ));
+    assert_eq!(
+        summarize(&diagnostics),
+        [
+            (
+                Severity::Error,
+                LintCode::MissingArchitecture,
+                Section::Base,
+            ),
+            (
+                Severity::Error,
+                LintCode::MissingArchitecture,
+                Section::Derivative(Name("foo-none")),
+            ),
+        ],
+    );
+
+    let diagnostics = lint(concat!(
+        "pkgbase = foo\n",
+        "\tpkgver = 1.0\n",
+        "\tpkgrel = 1\n",
+        "\tarch = x86_64\n",
+        "pkgname = foo\n",
+        "pkgname = foo-none\n",
+        "\tarch =\n",
+    ));
+    assert_eq!(
+        summarize(&diagnostics),
+        [(
+            Severity::Error,
+            LintCode::MissingArchitecture,
+            Section::Derivative(Name("foo-none")),
+        )],
+    );
+}
+
+#[test]
+fn unlisted_architecture() {
+    let diagnostics = lint(concat!(
+        "pkgbase = foo\n",
+        "\tpkgver = 1.0\n",
+        "\tpkgrel = 1\n",
+        "\tarch = x86_64\n",
+        "\tdepends_aarch64 = arm-helper\n",
+        "\tdepends_aarch64 = arm-runtime\n",
+        "\tsource_i686 = i686.patch\n",
+        "\tsha256sums_i686 = SKIP\n",
+        "pkgname = foo\n",
+        "pkgname = foo-arm\n",
+        "\tarch = aarch64\n",
+        "\tprovides_aarch64 = foo\n",
+        "\tprovides_x86_64 = foo\n",
+    ));
+    dbg!(&diagnostics);
+    let issues: Vec<_> = diagnostics
+        .iter()
+        .map(|diagnostic| match diagnostic.issue {
+            LintIssue::UnlistedArchitecture {
+                field,
+                architecture,
+            } => (diagnostic.section, field, architecture),
+            _ => panic!("unexpected diagnostic: {diagnostic}"),
+        })
+        .collect();
+    assert_eq!(
+        issues,
+        [
+            (Section::Base, FieldName::Source, Architecture("i686")),
+            (
+                Section::Base,
+                FieldName::Dependencies,
+                Architecture("aarch64")
+            ),
+            (
+                Section::Derivative(Name("foo-arm")),
+                FieldName::Provides,
+                Architecture("x86_64"),
+            ),
+            (
+                Section::Base,
+                FieldName::Sha256Checksums,
+                Architecture("i686"),
+            ),
+        ],
+    );
+    assert!(
+        diagnostics
+            .iter()
+            .all(|diagnostic| diagnostic.severity == Severity::Warning)
+    );
+}
+
+#[test]
+fn checksum_count_mismatch() {
+    let diagnostics = lint(concat!(
+        "pkgbase = foo\n",
+        "\tpkgver = 1.0\n",
+        "\tpkgrel = 1\n",
+        "\tarch = x86_64\n",
+        "\tarch = aarch64\n",
+        "\tsource = foo.tar.gz\n",
+        "\tsha256sums = SKIP\n",
+        "\tsource_aarch64 = foo.patch\n",
+        "\tsource_aarch64 = bar.patch\n",
+        "\tsha256sums_aarch64 = SKIP\n",
+        "pkgname = foo\n",
+    ));
+    assert_eq!(
+        messages(&diagnostics),
+        [
+            "error[checksum-count-mismatch] pkgbase: sha256sums_aarch64 of pkgbase has 1 entry but source_aarch64 has 2",
+        ],
+    );
+}
+
+#[test]
+fn unknown_no_extract() {
+    let diagnostics = lint(concat!(
+        "pkgbase = foo\n",
+        "\tpkgver = 1.0\n",
+        "\tpkgrel = 1\n",
+        "\tarch = any\n",
+        "\tsource = https://example.com/foo.tar.gz\n",
+        "\tsource = renamed.zip::https://example.com/download?id=1\n",
+        "\tsource = repo::fossil+https://fossil.example.com/foo\n",
+        "\tnoextract = foo.tar.gz\n",
+        "\tnoextract = renamed.zip\n",
+        "\tnoextract = download\n",
+        "\tnoextract = repo.fossil\n",
+        "\tnoextract = repo\n",
+        "\tsha256sums = SKIP\n",
+        "\tsha256sums = SKIP\n",
+        "\tsha256sums = SKIP\n",
+        "pkgname = foo\n",
+    ));
+    assert_eq!(
+        messages(&diagnostics),
+        [
+            r#"warning[unknown-no-extract] pkgbase: noextract entry FileName("download") is not the file name of any source"#,
+            r#"warning[unknown-no-extract] pkgbase: noextract entry FileName("repo") is not the file name of any source"#,
+        ],
+    );
+}
+
+#[test]
+fn absolute_backup() {
+    let diagnostics = lint(concat!(
+        "pkgbase = foo\n",
+        "\tpkgver = 1.0\n",
+        "\tpkgrel = 1\n",
+        "\tarch = any\n",
+        "pkgname = foo\n",
+        "\tbackup = etc/foo.conf\n",
+        "\tbackup = /etc/foo.d/default.conf\n",
+    ));
+    assert_eq!(
+        summarize(&diagnostics),
+        [(
+            Severity::Error,
+            LintCode::AbsoluteBackup,
+            Section::Derivative(Name("foo")),
+        )],
+    );
+}
+
+#[test]
+fn redundant_override() {
+    let diagnostics = lint(concat!(
+        "pkgbase = foo\n",
+        "\tpkgdesc = Foo\n",
+        "\tpkgver = 1.0\n",
+        "\tpkgrel = 1\n",
+        "\tarch = x86_64\n",
+        "\tdepends = glibc\n",
+        "\tdepends = gcc-libs\n",
+        "\tdepends_x86_64 = lib32-glibc\n",
+        "pkgname = foo\n",
+        "\tpkgdesc = Foo\n",
+        "\tdepends = glibc\n",
+        "\tdepends = gcc-libs\n",
+        "pkgname = foo-lite\n",
+        "\tpkgdesc = Lightweight foo\n",
+        "\tdepends = glibc\n",
+        "\tdepends_x86_64 = lib32-glibc\n",
+    ));
+    assert_eq!(
+        messages(&diagnostics),
+        [
+            "warning[redundant-override] pkgname foo: pkgdesc duplicates the values of pkgbase",
+            "warning[redundant-override] pkgname foo: depends duplicates the values of pkgbase",
+            "warning[redundant-override] pkgname foo-lite: depends_x86_64 duplicates the values of pkgbase",
+        ],
+    );
+}
+
+#[test]
+fn lint_code() {
+    assert_eq!(LintCode::UnknownNoExtract.to_string(), "unknown-no-extract");
+    assert_eq!(LintCode::AbsoluteBackup.as_ref(), "absolute-backup");
+    assert_eq!(LintCode::RedundantOverride.severity(), Severity::Warning);
+    assert_eq!(LintCode::InvalidName.severity(), Severity::Error);
+    assert!(Severity::Warning < Severity::Error);
+}
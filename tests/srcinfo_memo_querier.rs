@@ -6,8 +6,8 @@ pub use _utils::*;
 use arch_pkg_text::{
     srcinfo::{ChecksumArray, ChecksumsMut, FieldName, MemoQuerier, QueryItem, QueryMut, Section},
     value::{
-        Architecture, Base, Dependency, Description, License, Name, SkipOrArray, Source,
-        UpstreamVersion,
+        Architecture, Base, Dependency, Description, License, Name, ParsedRelease, SkipOrArray,
+        Source, UpstreamVersion,
     },
 };
 use hex_lit::hex;
@@ -35,7 +35,10 @@ fn assert_complex(querier: &mut MemoQuerier, cache_state: bool) {
         querier.version_mut().as_ref().map(UpstreamVersion::as_str),
         Some("12.34.56.r789"),
     );
-    assert_eq!(querier.release_mut().unwrap().parse().ok(), Some(2));
+    assert_eq!(
+        querier.release_mut().unwrap().parse(),
+        Ok(ParsedRelease::new(2, None))
+    );
 
     assert_eq!(querier.__has_cache(FieldName::Description, 1), cache_state);
     assert_eq!(querier.__has_cache(FieldName::Description, 2), cache_state);
@@ -202,7 +205,10 @@ fn assert_simple(querier: &mut MemoQuerier, cache_state: bool) {
     assert_eq!(querier.__has_cache(FieldName::Release, 0), cache_state);
     assert_eq!(querier.__has_cache(FieldName::Version, 0), cache_state);
     assert_eq!(querier.__has_cache(FieldName::Description, 0), cache_state);
-    assert_eq!(querier.release_mut().unwrap().parse().ok(), Some(1));
+    assert_eq!(
+        querier.release_mut().unwrap().parse(),
+        Ok(ParsedRelease::new(1, None))
+    );
     assert!(querier.__has_cache(FieldName::Release, 0));
     assert!(querier.__has_cache(FieldName::Version, 0));
     assert!(querier.__has_cache(FieldName::Description, 0));
@@ -0,0 +1,148 @@
+#![cfg(feature = "std")]
+
+pub mod _utils;
+pub use _utils::*;
+
+use arch_pkg_text::{
+    parse::ParsedSrcinfo,
+    srcinfo::{Query, QueryItem, Section},
+    value::{Architecture, Dependency, Description, License, Name, Source},
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn names() {
+    let srcinfo = ParsedSrcinfo::try_from(COMPLEX).unwrap();
+    let names: Vec<_> = srcinfo.packages().map(|package| package.name()).collect();
+    assert_eq!(names, [Name("foo-bin"), Name("bar-bin")]);
+    assert!(srcinfo.package("foo-bin").is_some());
+    assert!(srcinfo.package("complex-example-bin").is_none());
+    assert!(srcinfo.package("baz-bin").is_none());
+
+    let foo = srcinfo.package("foo-bin").unwrap();
+    let queried: Vec<_> = foo.derivative_names().collect();
+    assert_eq!(queried, [Name("foo-bin")]);
+}
+
+#[test]
+fn base_only_fields() {
+    let srcinfo = ParsedSrcinfo::try_from(COMPLEX).unwrap();
+    for package in srcinfo.packages() {
+        eprintln!("CASE: {}", package.name());
+        assert_eq!(package.version().unwrap().as_str(), "12.34.56.r789");
+        assert_eq!(package.release().unwrap().as_str(), "2");
+        assert_eq!(package.epoch().unwrap().as_str(), "3");
+        assert_eq!(package.base_name().unwrap().as_str(), "complex-example-bin");
+    }
+}
+
+#[test]
+fn single_fields() {
+    let srcinfo = ParsedSrcinfo::try_from(COMPLEX).unwrap();
+    let foo = srcinfo.package("foo-bin").unwrap();
+
+    let description: Vec<_> = foo.description().map(QueryItem::into_tuple3).collect();
+    assert_eq!(
+        description,
+        [(
+            Description("Description under foo-bin"),
+            Section::Derivative(Name("foo-bin")),
+            (),
+        )],
+    );
+
+    let url: Vec<_> = foo.url().map(|item| item.value.as_str()).collect();
+    assert_eq!(url, ["https://apps.example.com/complex/"]);
+}
+
+#[test]
+fn overridden_lists() {
+    let srcinfo = ParsedSrcinfo::try_from(COMPLEX).unwrap();
+    let foo = Section::Derivative(Name("foo-bin"));
+
+    let dependencies: Vec<_> = srcinfo
+        .package("foo-bin")
+        .unwrap()
+        .dependencies()
+        .map(QueryItem::into_tuple3)
+        .collect();
+    assert_eq!(
+        dependencies,
+        [
+            (
+                Dependency("aarch64-compatibility"),
+                foo,
+                Some(Architecture("aarch64")),
+            ),
+            (
+                Dependency("x86_64-compatibility-for-foo"),
+                foo,
+                Some(Architecture("x86_64")),
+            ),
+            (
+                Dependency("i686-compatibility-for-foo"),
+                foo,
+                Some(Architecture("i686")),
+            ),
+            (Dependency("extra-depend-for-foo"), foo, None),
+        ],
+    );
+
+    let architectures: Vec<_> = srcinfo
+        .package("foo-bin")
+        .unwrap()
+        .architecture()
+        .map(|item| item.value)
+        .collect();
+    assert_eq!(architectures, [Architecture("i686")]);
+
+    let sources: Vec<_> = srcinfo
+        .package("bar-bin")
+        .unwrap()
+        .source()
+        .map(|item| (item.value, item.architecture))
+        .collect();
+    assert_eq!(
+        sources,
+        [
+            (Source("build-x86_64.bash"), Some(Architecture("x86_64"))),
+            (Source("build-aarch64.bash"), Some(Architecture("aarch64"))),
+            (Source("build-bar.bash"), None),
+        ],
+    );
+}
+
+#[test]
+fn inherited_lists() {
+    let srcinfo = ParsedSrcinfo::try_from(COMPLEX).unwrap();
+    for package in srcinfo.packages() {
+        eprintln!("CASE: {}", package.name());
+        let section = Section::Derivative(package.name());
+
+        let licenses: Vec<_> = package.license().map(QueryItem::into_tuple3).collect();
+        assert_eq!(
+            licenses,
+            [
+                (License("MIT"), section, ()),
+                (License("ISC"), section, ()),
+                (License("Apache-2.0"), section, ()),
+            ],
+        );
+
+        let make_dependencies: Vec<_> =
+            package.make_dependencies().map(|item| item.value).collect();
+        assert_eq!(make_dependencies, [Dependency("bash")]);
+    }
+}
+
+#[test]
+fn single_package() {
+    let srcinfo = ParsedSrcinfo::try_from(SIMPLE).unwrap();
+    let base_dependencies: Vec<_> = srcinfo.base.dependencies().to_vec();
+    let package = srcinfo.packages().next().unwrap();
+    let dependencies: Vec<_> = package
+        .dependencies()
+        .map(|item| (item.value, item.architecture))
+        .collect();
+    assert_eq!(dependencies, base_dependencies);
+}
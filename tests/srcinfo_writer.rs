@@ -0,0 +1,168 @@
+#![cfg(feature = "std")]
+
+pub mod _utils;
+pub use _utils::*;
+
+use arch_pkg_text::{
+    parse::ParsedSrcinfo,
+    srcinfo::{FieldName, Query, Section},
+    value::Architecture,
+};
+use pretty_assertions::assert_eq;
+use std::collections::HashMap;
+
+const FIXTURES: &[(&str, &str)] = &[
+    ("complex", COMPLEX),
+    ("simple", SIMPLE),
+    ("has-empty-values", HAS_EMPTY_VALUES),
+    ("multiple-checksum-types", MULTIPLE_CHECKSUM_TYPES),
+];
+
+const FIELD_NAMES: &[FieldName] = &[
+    FieldName::Base,
+    FieldName::Name,
+    FieldName::Epoch,
+    FieldName::Release,
+    FieldName::ValidPgpKeys,
+    FieldName::Version,
+    FieldName::Architecture,
+    FieldName::Backup,
+    FieldName::ChangeLog,
+    FieldName::Description,
+    FieldName::Groups,
+    FieldName::InstallScript,
+    FieldName::License,
+    FieldName::NoExtract,
+    FieldName::Options,
+    FieldName::Source,
+    FieldName::Url,
+    FieldName::Dependencies,
+    FieldName::CheckDependencies,
+    FieldName::MakeDependencies,
+    FieldName::OptionalDependencies,
+    FieldName::Provides,
+    FieldName::Conflicts,
+    FieldName::Replaces,
+    FieldName::Crc32Checksums,
+    FieldName::Md5Checksums,
+    FieldName::Sha1Checksums,
+    FieldName::Sha224Checksums,
+    FieldName::Sha256Checksums,
+    FieldName::Sha384Checksums,
+    FieldName::Sha512Checksums,
+    FieldName::Blake2bChecksums,
+];
+
+type ValuesByLocation<'a> =
+    HashMap<(&'static str, Section<'a>, Option<Architecture<'a>>), Vec<&'a str>>;
+
+/// Collect the values of every field grouped by field name, section, and architecture.
+///
+/// The writer is allowed to reorder entries of different fields or of different architectures,
+/// but not the values within each group.
+fn values_by_location<'a>(srcinfo: &ParsedSrcinfo<'a>) -> ValuesByLocation<'a> {
+    let mut values = ValuesByLocation::new();
+    for field_name in FIELD_NAMES {
+        for item in srcinfo.query_raw_text(*field_name) {
+            values
+                .entry((field_name.as_ref(), item.section, item.architecture))
+                .or_default()
+                .push(item.value);
+        }
+    }
+    values
+}
+
+#[test]
+fn round_trip() {
+    for (name, text) in FIXTURES {
+        eprintln!("CASE: {name}");
+        let parsed = ParsedSrcinfo::parse(text).try_into_complete().unwrap();
+        let written = parsed.to_string();
+        eprintln!("WRITTEN:\n{written}");
+        let reparsed = ParsedSrcinfo::parse(&written).try_into_complete().unwrap();
+        assert_eq!(values_by_location(&reparsed), values_by_location(&parsed));
+        assert_eq!(
+            reparsed.derivatives.keys().collect::<Vec<_>>(),
+            parsed.derivatives.keys().collect::<Vec<_>>(),
+        );
+    }
+}
+
+#[test]
+fn idempotent() {
+    for (name, text) in FIXTURES {
+        eprintln!("CASE: {name}");
+        let written = ParsedSrcinfo::parse(text)
+            .try_into_complete()
+            .unwrap()
+            .to_string();
+        let rewritten = ParsedSrcinfo::parse(&written)
+            .try_into_complete()
+            .unwrap()
+            .to_string();
+        assert_eq!(rewritten, written);
+    }
+}
+
+#[test]
+fn makepkg_order() {
+    let written = ParsedSrcinfo::parse(COMPLEX)
+        .try_into_complete()
+        .unwrap()
+        .to_string();
+    let expected = [
+        "pkgbase = complex-example-bin",
+        "\tpkgdesc = Description under pkgbase",
+        "\tpkgver = 12.34.56.r789",
+        "\tpkgrel = 2",
+        "\tepoch = 3",
+        "\turl = https://apps.example.com/complex/",
+        "\tarch = x86_64",
+        "\tarch = aarch64",
+        "\tlicense = MIT",
+        "\tlicense = ISC",
+        "\tlicense = Apache-2.0",
+        "\tmakedepends = bash",
+        "\tdepends = glibc>=2.0",
+        "\tdepends = coreutils",
+        "\tdepends = linux",
+        "\toptdepends = example-plugins: Plugins for complex-example",
+        "\tsource = https://src.apps.example.com/complex.tgz",
+        "\tsha1sums = 4808c01d2da9ba8a1f0da603d20d515e3e7a67e6",
+        "\tsource_x86_64 = build-x86_64.bash",
+        "\tsha1sums_x86_64 = SKIP",
+        "\tsource_aarch64 = build-aarch64.bash",
+        "\tdepends_aarch64 = aarch64-compatibility",
+        "\tsha1sums_aarch64 = SKIP",
+        "",
+        "pkgname = foo-bin",
+        "\tpkgdesc = Description under foo-bin",
+        "\tinstall = foo-bin.install",
+        "\tarch = i686",
+        "\tdepends = extra-depend-for-foo",
+        "\tprovides = foo",
+        "\tsource = build-foo.bash",
+        "\tsha1sums = SKIP",
+        "\tdepends_i686 = i686-compatibility-for-foo",
+        "\tdepends_x86_64 = x86_64-compatibility-for-foo",
+        "",
+        "pkgname = bar-bin",
+        "\tpkgdesc = Description under bar-bin",
+        "\tinstall = bar-bin.install",
+        "\tdepends = extra-depend-for-bar",
+        "\tprovides = bar",
+        "\tsource = build-bar.bash",
+        "\tsha1sums = SKIP",
+        "\tdepends_x86_64 = x86_64-compatibility-for-bar",
+        "",
+    ]
+    .map(|line| format!("{line}\n"))
+    .concat();
+    assert_eq!(written, expected);
+}
+
+#[test]
+fn write_nothing() {
+    assert_eq!(ParsedSrcinfo::default().to_string(), "\n");
+}
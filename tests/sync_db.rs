@@ -0,0 +1,129 @@
+#![cfg(feature = "sync_db")]
+
+use arch_pkg_text::{
+    db::sync::{ReadSyncDbError, SyncDb, SyncDbPackageError},
+    desc::Query as _,
+    files::Query as _,
+    parse::ParsedDesc,
+    value::{FilePath, Name},
+};
+use pretty_assertions::assert_eq;
+
+const FOO_DESC: &str = include_str!("fixtures/sync-db/packages/foo-1.0-1/desc");
+const BAR_DESC: &str = include_str!("fixtures/sync-db/packages/bar-2.0-1/desc");
+
+const DB_ARCHIVES: &[(&str, &[u8])] = &[
+    ("example.db", include_bytes!("fixtures/sync-db/example.db")),
+    (
+        "example.db.tar",
+        include_bytes!("fixtures/sync-db/example.db.tar"),
+    ),
+    (
+        "example.db.tar.zst",
+        include_bytes!("fixtures/sync-db/example.db.tar.zst"),
+    ),
+];
+
+const FILES_ARCHIVE: &[u8] = include_bytes!("fixtures/sync-db/example.files");
+
+#[test]
+fn read_db() {
+    for (name, archive) in DB_ARCHIVES {
+        eprintln!("CASE: {name}");
+        let db = SyncDb::read(*archive).unwrap();
+        assert_eq!(db.len(), 2);
+        let packages: Vec<_> = db
+            .packages()
+            .map(Result::unwrap)
+            .map(|package| (package.directory, package.desc, package.files.is_some()))
+            .collect();
+        assert_eq!(
+            packages,
+            [
+                ("foo-1.0-1", ParsedDesc::parse(FOO_DESC).unwrap(), false),
+                ("bar-2.0-1", ParsedDesc::parse(BAR_DESC).unwrap(), false),
+            ],
+        );
+    }
+}
+
+#[test]
+fn read_files() {
+    let db = SyncDb::read(FILES_ARCHIVE).unwrap();
+    let packages: Vec<_> = db
+        .packages()
+        .map(Result::unwrap)
+        .map(|package| {
+            let name = package.desc.name().unwrap();
+            let files: Vec<_> = package
+                .files
+                .unwrap()
+                .files()
+                .unwrap()
+                .into_iter()
+                .collect();
+            (name, files)
+        })
+        .collect();
+    assert_eq!(
+        packages,
+        [
+            (
+                Name("foo"),
+                vec![
+                    FilePath("usr/"),
+                    FilePath("usr/bin/"),
+                    FilePath("usr/bin/foo"),
+                ],
+            ),
+            (
+                Name("bar"),
+                vec![
+                    FilePath("usr/"),
+                    FilePath("usr/lib/"),
+                    FilePath("usr/lib/libbar.so.2"),
+                ],
+            ),
+        ],
+    );
+}
+
+#[test]
+fn open() {
+    let db = SyncDb::open("tests/fixtures/sync-db/example.db").unwrap();
+    assert_eq!(db.len(), 2);
+}
+
+#[test]
+fn empty_archive() {
+    let db = SyncDb::read(&[0u8; 1024][..]).unwrap();
+    assert!(db.is_empty());
+    assert_eq!(db.packages().count(), 0);
+}
+
+#[test]
+fn invalid_archive() {
+    let error = SyncDb::read(&b"\x1F\x8Bnot a gzip stream"[..]).unwrap_err();
+    assert!(matches!(error, ReadSyncDbError::Io(_)), "{error:?}");
+}
+
+#[test]
+fn missing_desc() {
+    let mut builder = tar::Builder::new(Vec::new());
+    let content = b"%FILES%\nusr/\n";
+    let mut header = tar::Header::new_ustar();
+    header.set_size(content.len() as u64);
+    header.set_mode(0o644);
+    header.set_cksum();
+    builder
+        .append_data(&mut header, "foo-1.0-1/files", &content[..])
+        .unwrap();
+    let archive = builder.into_inner().unwrap();
+
+    let db = SyncDb::read(archive.as_slice()).unwrap();
+    let errors: Vec<_> = db.packages().map(Result::unwrap_err).collect();
+    assert!(matches!(
+        errors.as_slice(),
+        [SyncDbPackageError::MissingDesc("foo-1.0-1")],
+    ));
+}
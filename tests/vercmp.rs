@@ -0,0 +1,212 @@
+use arch_pkg_text::value::Version;
+use core::cmp::Ordering::{self, Equal, Greater, Less};
+use pretty_assertions::assert_eq;
+
+/// Pairs of versions and the expected result of `vercmp $left $right`.
+const CASES: &[(&str, &str, Ordering)] = &[
+    // equality
+    ("1.0", "1.0", Equal),
+    ("1.5.0", "1.5.0", Equal),
+    ("1.0alpha", "1.0alpha", Equal),
+    // simple
+    ("1.0", "1.1", Less),
+    ("1.1", "1.0", Greater),
+    ("1.5.0", "1.5.1", Less),
+    ("1.5.1", "1.5.0", Greater),
+    ("2.0", "10.0", Less),
+    ("1.9", "1.10", Less),
+    ("1.10", "1.9", Greater),
+    ("20240101", "2.0", Greater),
+    // leading zeros
+    ("1.001", "1.1", Equal),
+    ("1.010", "1.10", Equal),
+    ("1.0010", "1.1", Greater),
+    ("0001", "1", Equal),
+    // numbers which don't fit in 64 bits
+    ("1.99999999999999999999", "1.100000000000000000000", Less),
+    ("18446744073709551616", "18446744073709551615", Greater),
+    // mixed length
+    ("1.5.0", "1.5", Greater),
+    ("1.5.1", "1.5", Greater),
+    ("1.5", "1.5.0", Less),
+    ("1.0", "1.0.1", Less),
+    // with pkgrel, simple
+    ("1.5.0-1", "1.5.0-1", Equal),
+    ("1.5.0-1", "1.5.0-2", Less),
+    ("1.5.0-1", "1.5.1-1", Less),
+    ("1.5.0-2", "1.5.1-1", Less),
+    ("1.5-10", "1.5-9", Greater),
+    // with pkgrel, mixed lengths
+    ("1.5-1", "1.5.1-1", Less),
+    ("1.5-2", "1.5.1-1", Less),
+    ("1.5-2", "1.5.1-2", Less),
+    // with pkgrel, subreleases
+    ("1.5-1", "1.5-1.1", Less),
+    ("1.5-1.1", "1.5-2", Less),
+    ("1.5-1.1", "1.5-1.2", Less),
+    ("1.5-1.10", "1.5-1.9", Greater),
+    ("1.5-1.0", "1.5-1", Greater),
+    // mixed pkgrel inclusion
+    ("1.5", "1.5-1", Equal),
+    ("1.5-1", "1.5", Equal),
+    ("1.1-1", "1.1", Equal),
+    ("1.0-1", "1.1", Less),
+    ("1.1-1", "1.0", Greater),
+    // alphanumeric versions
+    ("1.5b-1", "1.5-1", Less),
+    ("1.5b", "1.5", Less),
+    ("1.5b-1", "1.5", Less),
+    ("1.5b", "1.5.1", Less),
+    // pre-releases
+    ("1.0a", "1.0alpha", Less),
+    ("1.0alpha", "1.0b", Less),
+    ("1.0b", "1.0beta", Less),
+    ("1.0beta", "1.0rc", Less),
+    ("1.0rc", "1.0", Less),
+    ("1.0alpha", "1.0", Less),
+    ("1.0alpha1", "1.0alpha2", Less),
+    ("1.0rc1", "1.0", Less),
+    ("1.0rc1", "1.0rc2", Less),
+    ("1.0rc10", "1.0rc9", Greater),
+    ("1.0rc1", "1.0.1", Less),
+    ("1.0rc1", "0.9", Greater),
+    // letters against numbers
+    ("a", "1", Less),
+    ("1", "a", Greater),
+    ("abc", "abd", Less),
+    ("r123.abc", "r124.abc", Less),
+    ("6.1.arch1", "6.1.0", Less),
+    ("6.1.arch1", "6.1", Greater),
+    // alpha-dotted versions
+    ("1.5.a", "1.5", Greater),
+    ("1.5.b", "1.5.a", Greater),
+    ("1.5.1", "1.5.b", Greater),
+    // alpha dots and dashes
+    ("1.5.b-1", "1.5.b", Equal),
+    ("1.5-1", "1.5.b", Less),
+    // version control snapshots
+    ("1.2.3.r4.gabcdef0", "1.2.3", Greater),
+    ("1.2.3.r4.gabcdef0", "1.2.4", Less),
+    ("1.2.3.r4.gabcdef0", "1.2.3.r5.g0123456", Less),
+    ("r1234.abcdef0", "r1235.0123456", Less),
+    // same/similar content, differing separators
+    ("2.0", "2_0", Equal),
+    ("2.0_a", "2_0.a", Equal),
+    ("2.0a", "2.0.a", Less),
+    ("2___a", "2_a", Greater),
+    ("1+2", "1@2", Equal),
+    ("1..2", "1.2", Greater),
+    // epoch included version comparisons
+    ("0:1.0", "0:1.0", Equal),
+    ("0:1.0", "0:1.1", Less),
+    ("1:1.0", "0:1.0", Greater),
+    ("1:1.0", "0:1.1", Greater),
+    ("1:1.0", "2:1.1", Less),
+    ("10:1.0", "9:2.0", Greater),
+    // epoch + sometimes present pkgrel
+    ("1:1.0", "0:1.0-1", Greater),
+    ("1:1.0-1", "0:1.1-1", Greater),
+    ("1:1.0-1", "1:1.0-2", Less),
+    ("2:1.0-1", "1:99-99", Greater),
+    // epoch included on one version
+    ("0:1.0", "1.0", Equal),
+    ("0:1.0", "1.1", Less),
+    ("0:1.1", "1.0", Greater),
+    ("1:1.0", "1.0", Greater),
+    ("1:1.0", "1.1", Greater),
+    ("1:1.1", "1.1", Greater),
+    ("1:0.1-1", "2.0-1", Greater),
+    (":1.0", "1.0", Equal),
+];
+
+#[test]
+fn vercmp() {
+    for (left, right, expected) in CASES {
+        eprintln!("CASE: {left} {right} -> {expected:?}");
+        assert_eq!(Version(left).vercmp(&Version(right)), *expected);
+        assert_eq!(Version(right).vercmp(&Version(left)), expected.reverse());
+    }
+}
+
+#[test]
+fn parsed_version_agrees_with_vercmp() {
+    let mut count = 0;
+    for (left, right, expected) in CASES {
+        let (Ok(left), Ok(right)) = (Version(left).parse(), Version(right).parse()) else {
+            continue;
+        };
+        eprintln!("CASE: {left:?} {right:?} -> {expected:?}");
+        assert_eq!(left.cmp(&right), *expected);
+        assert_eq!(left == right, *expected == Equal);
+        count += 1;
+    }
+    assert!(count > 10, "too few cases were tested: {count}");
+}
+
+#[test]
+fn sort() {
+    let mut versions = [
+        "1:0.1-1",
+        "1.0-1",
+        "1.0rc1-1",
+        "1.0.1-1",
+        "1.0-2",
+        "1.0alpha-1",
+        "0.9-1",
+        "1.0.a-1",
+        "1.0beta-1",
+    ]
+    .map(|version| Version(version).parse().unwrap());
+    versions.sort();
+    let versions = versions.map(|version| {
+        let (epoch, upstream, release) = version.components();
+        match epoch {
+            None => format!("{upstream}-{release}"),
+            Some(epoch) => format!("{epoch}:{upstream}-{release}"),
+        }
+    });
+    assert_eq!(
+        versions,
+        [
+            "0.9-1",
+            "1.0alpha-1",
+            "1.0beta-1",
+            "1.0rc1-1",
+            "1.0-1",
+            "1.0-2",
+            "1.0.a-1",
+            "1.0.1-1",
+            "1:0.1-1",
+        ],
+    );
+}
+
+#[test]
+fn subrelease() {
+    let parse = |version| Version(version).parse().unwrap();
+    assert!(parse("1.0-1") < parse("1.0-1.1"));
+    assert!(parse("1.0-1.1") < parse("1.0-1.2"));
+    assert!(parse("1.0-1.2") < parse("1.0-2"));
+    assert!(parse("1.0-1.9") < parse("1.0-1.10"));
+    assert_eq!(parse("1.0-1.01"), parse("1.0-1.1"));
+    assert_eq!(parse("1.0-1.1").components().2.to_string(), "1.1");
+}
+
+#[test]
+fn constraint_version_agrees_with_vercmp() {
+    let mut count = 0;
+    for (left, right, expected) in CASES {
+        let (Ok(constraint), Ok(version)) =
+            (Version(left).parse_constraint(), Version(right).parse())
+        else {
+            continue;
+        };
+        eprintln!("CASE: {constraint:?} {version:?} -> {expected:?}");
+        assert_eq!(constraint.compare_version(&version), expected.reverse());
+        assert_eq!(constraint.partial_cmp(&version), Some(*expected));
+        assert_eq!(version.partial_cmp(&constraint), Some(expected.reverse()));
+        assert_eq!(constraint == version, *expected == Equal);
+        count += 1;
+    }
+    assert!(count > 10, "too few cases were tested: {count}");
+}